@@ -0,0 +1,186 @@
+//! Este módulo fornece uma posição geodésica com altitude e sua conversão para coordenadas
+//! ECEF (Earth-Centered, Earth-Fixed), usadas para cálculos de posicionamento tridimensional
+//! que a representação de superfície (`Vertex`) não consegue expressar.
+//!
+//! # Notas
+//! - As conversões usam o elipsoide WGS84, com os mesmos parâmetros definidos no módulo `consts`.
+//! - A conversão de geodésico para ECEF é direta; a conversão inversa usa o método iterativo de
+//!   Bowring para recuperar a latitude.
+
+use crate::{
+    consts::{FLATTENING, SEMI_MAJOR_AXIS_LENGTH},
+    math::conversion::degrees_to_radians,
+};
+
+use super::vertex::Vertex;
+
+/// Uma posição geodésica com altitude, em coordenadas WGS84.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeodeticPosition {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Altitude elipsoidal, em metros, acima da superfície do elipsoide WGS84.
+    pub altitude: f64,
+}
+
+impl GeodeticPosition {
+    /// Cria uma nova posição geodésica.
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Cria uma posição geodésica a partir de um `Vertex` de superfície e uma altitude.
+    pub fn from_vertex(vertex: &Vertex, altitude: f64) -> Self {
+        Self::new(vertex.latitude, vertex.longitude, altitude)
+    }
+
+    /// Projeta a posição na superfície, descartando a altitude.
+    pub fn to_vertex(&self) -> Vertex {
+        Vertex::new(self.latitude, self.longitude)
+    }
+
+    /// Converte a posição geodésica para coordenadas ECEF (Earth-Centered, Earth-Fixed).
+    ///
+    /// # Fórmula
+    /// ```text
+    /// N = a / sqrt(1 − e²·sin²φ)
+    /// X = (N + h)·cosφ·cosλ
+    /// Y = (N + h)·cosφ·sinλ
+    /// Z = (N·(1 − e²) + h)·sinφ
+    /// ```
+    /// Onde `e² = 2f − f²` é a excentricidade ao quadrado do elipsoide.
+    pub fn to_ecef(&self) -> EcefPosition {
+        let phi = degrees_to_radians(self.latitude);
+        let lambda = degrees_to_radians(self.longitude);
+
+        let e_squared = 2.0 * FLATTENING - FLATTENING * FLATTENING;
+        let prime_vertical_radius =
+            SEMI_MAJOR_AXIS_LENGTH / (1.0 - e_squared * phi.sin().powi(2)).sqrt();
+
+        let x = (prime_vertical_radius + self.altitude) * phi.cos() * lambda.cos();
+        let y = (prime_vertical_radius + self.altitude) * phi.cos() * lambda.sin();
+        let z = (prime_vertical_radius * (1.0 - e_squared) + self.altitude) * phi.sin();
+
+        EcefPosition { x, y, z }
+    }
+}
+
+/// Uma posição em coordenadas cartesianas ECEF (Earth-Centered, Earth-Fixed), em metros.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EcefPosition {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl EcefPosition {
+    /// Converte coordenadas ECEF de volta para uma posição geodésica, usando o método
+    /// iterativo de Bowring para recuperar a latitude.
+    pub fn to_geodetic(&self) -> GeodeticPosition {
+        let e_squared = 2.0 * FLATTENING - FLATTENING * FLATTENING;
+        let semi_minor_axis = SEMI_MAJOR_AXIS_LENGTH * (1.0 - FLATTENING);
+        let e_prime_squared = (SEMI_MAJOR_AXIS_LENGTH.powi(2) - semi_minor_axis.powi(2))
+            / semi_minor_axis.powi(2);
+
+        let p = (self.x * self.x + self.y * self.y).sqrt();
+        let lambda = self.y.atan2(self.x);
+
+        // Latitude paramétrica inicial de Bowring.
+        let mut theta = (self.z * SEMI_MAJOR_AXIS_LENGTH).atan2(p * semi_minor_axis);
+
+        let mut phi = 0.0;
+        for _ in 0..5 {
+            phi = (self.z + e_prime_squared * semi_minor_axis * theta.sin().powi(3))
+                .atan2(p - e_squared * SEMI_MAJOR_AXIS_LENGTH * theta.cos().powi(3));
+            theta = ((1.0 - FLATTENING) * phi.sin()).atan2(phi.cos());
+        }
+
+        let prime_vertical_radius =
+            SEMI_MAJOR_AXIS_LENGTH / (1.0 - e_squared * phi.sin().powi(2)).sqrt();
+        let altitude = p / phi.cos() - prime_vertical_radius;
+
+        GeodeticPosition::new(phi.to_degrees(), lambda.to_degrees(), altitude)
+    }
+
+    /// Calcula a distância euclidiana (em linha reta, em corda) entre duas posições ECEF.
+    pub fn euclidean_distance(&self, other: &Self) -> f64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Converte `position` para ECEF e de volta, verificando que a latitude, longitude e
+    /// altitude recuperadas ficam muito próximas das originais.
+    fn assert_round_trips(position: GeodeticPosition) {
+        let ecef = position.to_ecef();
+        let roundtrip = ecef.to_geodetic();
+
+        assert!(
+            (roundtrip.latitude - position.latitude).abs() < 1e-9,
+            "latitude ida-e-volta divergiu: esperado {}, obtido {}",
+            position.latitude,
+            roundtrip.latitude
+        );
+        assert!(
+            (roundtrip.longitude - position.longitude).abs() < 1e-9,
+            "longitude ida-e-volta divergiu: esperado {}, obtido {}",
+            position.longitude,
+            roundtrip.longitude
+        );
+        assert!(
+            (roundtrip.altitude - position.altitude).abs() < 1e-3,
+            "altitude ida-e-volta divergiu: esperado {}, obtido {}",
+            position.altitude,
+            roundtrip.altitude
+        );
+    }
+
+    #[test]
+    fn test_round_trip_at_equator_sea_level() {
+        assert_round_trips(GeodeticPosition::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_round_trip_at_mid_latitude_with_altitude() {
+        assert_round_trips(GeodeticPosition::new(45.0, -23.5, 1500.0));
+    }
+
+    #[test]
+    fn test_round_trip_in_southern_hemisphere() {
+        assert_round_trips(GeodeticPosition::new(-33.45, -70.66, 520.0));
+    }
+
+    #[test]
+    fn test_round_trip_near_north_pole() {
+        assert_round_trips(GeodeticPosition::new(89.9, 10.0, 100.0));
+    }
+
+    #[test]
+    fn test_round_trip_near_south_pole() {
+        assert_round_trips(GeodeticPosition::new(-89.9, -170.0, -50.0));
+    }
+
+    #[test]
+    fn test_to_vertex_drops_altitude() {
+        let position = GeodeticPosition::new(10.0, 20.0, 300.0);
+
+        assert_eq!(position.to_vertex(), Vertex::new(10.0, 20.0));
+    }
+
+    #[test]
+    fn test_euclidean_distance_between_coincident_points_is_zero() {
+        let position = GeodeticPosition::new(12.0, 34.0, 0.0).to_ecef();
+
+        assert_eq!(position.euclidean_distance(&position), 0.0);
+    }
+}
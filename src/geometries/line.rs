@@ -1,6 +1,6 @@
-use std::{f64::consts::PI, fmt::Display};
+use std::fmt::Display;
 
-use crate::consts::EARTH_RADIUS_KM;
+use crate::math::distance::{calculate_earth_radius_distance_haversine, Distance};
 
 use super::vertex::Vertex;
 
@@ -31,26 +31,9 @@ impl Line {
         starting_vertex != ending_vertex
     }
 
-    /// Converte graus para radianos.
-    fn degrees_to_radians(degree: f64) -> f64 {
-        degree * PI / 180.0
-    }
-
     /// Calcula a distância da linha usando a fórmula de Haversine.
-    pub fn calculate_earth_radius_distance(&self) -> f64 {
-        let lat1_rad = Self::degrees_to_radians(self.starting_vertex.latitude);
-        let lat2_rad = Self::degrees_to_radians(self.ending_vertex.latitude);
-        let lon1_rad = Self::degrees_to_radians(self.starting_vertex.longitude);
-        let lon2_rad = Self::degrees_to_radians(self.ending_vertex.longitude);
-
-        let dist_lat = lat2_rad - lat1_rad;
-        let dist_lon = lon2_rad - lon1_rad;
-
-        let a = (dist_lat / 2.0).sin().powi(2)
-            + lat1_rad.cos() * lat2_rad.cos() * (dist_lon / 2.0).sin().powi(2);
-        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
-
-        EARTH_RADIUS_KM * c
+    pub fn calculate_earth_radius_distance(&self) -> Distance {
+        calculate_earth_radius_distance_haversine(&self.starting_vertex, &self.ending_vertex)
     }
 
     /// Calcula a distância euclidiana em 2D.
@@ -59,6 +42,20 @@ impl Line {
         let dy = self.ending_vertex.longitude - self.starting_vertex.longitude;
         (dx * dx + dy * dy).sqrt()
     }
+
+    /// Calcula o rumo inicial (azimute direto) da linha, de `starting_vertex` para `ending_vertex`.
+    ///
+    /// Retorna o rumo em graus, medido no sentido horário a partir do norte, normalizado para [0, 360).
+    pub fn initial_bearing(&self) -> f64 {
+        crate::math::distance::initial_bearing(&self.starting_vertex, &self.ending_vertex)
+    }
+
+    /// Calcula o rumo final (azimute de chegada) da linha em `ending_vertex`.
+    ///
+    /// Retorna o rumo em graus, normalizado para [0, 360).
+    pub fn final_bearing(&self) -> f64 {
+        crate::math::distance::final_bearing(&self.starting_vertex, &self.ending_vertex)
+    }
 }
 
 impl Display for Line {
@@ -75,6 +72,7 @@ impl Display for Line {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::consts::EARTH_RADIUS_KM;
 
     #[test]
     fn test_line_creation_success() {
@@ -125,8 +123,19 @@ mod tests {
         let line = Line::new(vertex_a, vertex_b).unwrap();
 
         // Verifica se a distância haversine está próxima do esperado
-        let expected_distance = EARTH_RADIUS_KM * PI / 180.0; // Aproximadamente 1 grau em radianos
-        assert!((line.calculate_earth_radius_distance() - expected_distance).abs() < 0.001);
+        let expected_distance = EARTH_RADIUS_KM * std::f64::consts::PI / 180.0; // Aproximadamente 1 grau em radianos
+        assert!((line.calculate_earth_radius_distance().kilometers() - expected_distance).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_line_initial_bearing() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(0.0, 1.0);
+
+        let line = Line::new(vertex_a, vertex_b).unwrap();
+
+        // Seguindo o equador para leste, o rumo inicial deve ser aproximadamente 90°
+        assert!((line.initial_bearing() - 90.0).abs() < 0.001);
     }
 
     #[test]
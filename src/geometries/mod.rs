@@ -0,0 +1,20 @@
+//! Este módulo agrupa os tipos geométricos usados para representar posições e formas sobre a
+//! superfície da Terra.
+//!
+//! ## Submódulos
+//!
+//! - [`vertex`]: Um ponto 2D em coordenadas geográficas (latitude/longitude).
+//! - [`line`]: Um segmento de reta entre dois [`vertex::Vertex`].
+//! - [`path`]: Uma sequência conectada de [`line::Line`]s.
+//! - [`geodetic`]: Posições com altitude e sua conversão para coordenadas ECEF (Earth-Centered,
+//!   Earth-Fixed), para cálculos que não podem ser expressos apenas na superfície.
+//! - [`segment`]: Trechos de caminho retos ou curvos (arcos), usados por rotas de Dubins.
+//! - [`polygon`]: Um polígono simples construído a partir de um caminho fechado, com área,
+//!   teste de contenção de ponto e roteamento ao seu redor por grafo de visibilidade.
+
+pub mod geodetic;
+pub mod line;
+pub mod path;
+pub mod polygon;
+pub mod segment;
+pub mod vertex;
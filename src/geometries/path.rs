@@ -1,9 +1,54 @@
-use super::line::Line;
+use super::{
+    line::Line,
+    segment::{normalize_angle, Arc, Segment, HALF_PI},
+    vertex::Vertex,
+};
 use rayon::prelude::*;
 
-pub enum PathCalcFullDistanceOptions {
-    Euclidean,
-    EarthRadius,
+/// Um espaço métrico sobre vértices: define como a distância entre dois pontos é medida.
+///
+/// Substituir a enumeração fechada por um trait permite que quem consome a biblioteca plugue
+/// suas próprias métricas (por exemplo, Vincenty ou Chebyshev) sem modificar este crate.
+pub trait Metric {
+    /// Calcula a distância entre dois vértices segundo esta métrica.
+    fn distance(&self, a: &Vertex, b: &Vertex) -> f64;
+}
+
+/// Distância euclidiana em 2D, tratando latitude/longitude como um plano cartesiano.
+pub struct Euclidean;
+
+impl Metric for Euclidean {
+    fn distance(&self, a: &Vertex, b: &Vertex) -> f64 {
+        let dx = a.latitude - b.latitude;
+        let dy = a.longitude - b.longitude;
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+
+/// Distância de grande círculo sobre a superfície da Terra, usando a fórmula de Haversine.
+pub struct Haversine;
+
+impl Metric for Haversine {
+    fn distance(&self, a: &Vertex, b: &Vertex) -> f64 {
+        crate::math::distance::calculate_earth_radius_distance_haversine(a, b).kilometers()
+    }
+}
+
+/// Distância de quarteirão (Manhattan): soma das diferenças absolutas de latitude e longitude.
+///
+/// Útil para roteamento alinhado a grade, onde o deslocamento diagonal não é permitido.
+pub struct Manhattan;
+
+impl Metric for Manhattan {
+    fn distance(&self, a: &Vertex, b: &Vertex) -> f64 {
+        (a.latitude - b.latitude).abs() + (a.longitude - b.longitude).abs()
+    }
+}
+
+/// Verifica se o fim de `last_line` coincide com o início de `current_line`, isto é, se as duas
+/// linhas formam um caminho conectado.
+fn lines_are_connected(last_line: &Line, current_line: &Line) -> bool {
+    last_line.ending_vertex == current_line.starting_vertex
 }
 
 pub struct Path<const N: usize> {
@@ -14,7 +59,7 @@ impl<const N: usize> Path<N> {
     pub fn new(lines: [Line; N]) -> Result<Self, String> {
         // Valida todas as linhas no array
         for i in 1..lines.len() {
-            if !Self::path_is_valid(&lines[i - 1], &lines[i]) {
+            if !lines_are_connected(&lines[i - 1], &lines[i]) {
                 return Err(format!(
                     "Caminho inconsistente: linha {} não se conecta com a linha {}",
                     i - 1,
@@ -26,28 +71,608 @@ impl<const N: usize> Path<N> {
         Ok(Self { lines })
     }
 
-    // Valida se o caminho e valido
-    fn path_is_valid(last_line: &Line, current_line: &Line) -> bool {
-        last_line.ending_vertex == current_line.starting_vertex
+    /// Calcula a distância total do caminho segundo a métrica escolhida, com suporte a soma
+    /// paralela.
+    pub fn calculate_full_distance<M: Metric + Sync>(&self, metric: &M) -> f64 {
+        self.lines
+            .par_iter() // Iterador paralelo
+            .map(|line| metric.distance(&line.starting_vertex, &line.ending_vertex))
+            .sum() // Soma os resultados em paralelo
     }
 
-    /// Calcula a distância total do caminho, com suporte a metodologia paralela.
-    pub fn calculate_full_distance(&self, methodology: PathCalcFullDistanceOptions) -> f64 {
-        match methodology {
-            PathCalcFullDistanceOptions::Euclidean => self
-                .lines
-                .par_iter() // Iterador paralelo
-                .map(|line| line.calculate_euclidean_distance()) // Calcula distância euclidiana para cada linha
-                .sum(), // Soma os resultados em paralelo
-            PathCalcFullDistanceOptions::EarthRadius => self
-                .lines
-                .par_iter() // Iterador paralelo
-                .map(|line| line.calculate_earth_radius_distance()) // Calcula distância Haversine para cada linha
-                .sum(), // Soma os resultados em paralelo
+    /// Calcula a tabela de comprimento de arco acumulado: `cumulative[i]` é a distância do
+    /// início do caminho até o início da linha `i`, e o último elemento é o comprimento total.
+    fn cumulative_lengths<M: Metric>(&self, metric: &M) -> Vec<f64> {
+        let mut cumulative = Vec::with_capacity(self.lines.len() + 1);
+        cumulative.push(0.0);
+
+        let mut total = 0.0;
+        for line in &self.lines {
+            total += metric.distance(&line.starting_vertex, &line.ending_vertex);
+            cumulative.push(total);
         }
+
+        cumulative
+    }
+
+    /// Retorna o vértice interpolado a uma distância `d` (medida a partir do início do
+    /// caminho) ao longo do caminho, segundo a métrica escolhida.
+    ///
+    /// Retorna o vértice inicial para `d <= 0`, o vértice final para `d` maior ou igual ao
+    /// comprimento total, e `None` para um caminho vazio.
+    pub fn point_at_distance<M: Metric>(&self, d: f64, metric: &M) -> Option<Vertex> {
+        if self.lines.is_empty() {
+            return None;
+        }
+
+        if d <= 0.0 {
+            return Some(self.lines[0].starting_vertex);
+        }
+
+        let cumulative = self.cumulative_lengths(metric);
+        let total_length = *cumulative.last().unwrap();
+        let target = d.min(total_length);
+
+        let segment_index = match cumulative
+            .binary_search_by(|length| length.partial_cmp(&target).unwrap())
+        {
+            Ok(index) => index.min(self.lines.len() - 1),
+            Err(index) => index.saturating_sub(1).min(self.lines.len() - 1),
+        };
+
+        let line = &self.lines[segment_index];
+        let segment_start = cumulative[segment_index];
+        let segment_length = cumulative[segment_index + 1] - segment_start;
+
+        let t = if segment_length == 0.0 {
+            0.0
+        } else {
+            (target - segment_start) / segment_length
+        };
+
+        Some(Vertex::new(
+            line.starting_vertex.latitude
+                + t * (line.ending_vertex.latitude - line.starting_vertex.latitude),
+            line.starting_vertex.longitude
+                + t * (line.ending_vertex.longitude - line.starting_vertex.longitude),
+        ))
+    }
+
+    /// Reamostra o caminho em vértices espaçados uniformemente por `spacing` unidades de
+    /// distância acumulada (densificação por comprimento de arco).
+    ///
+    /// Inclui sempre o vértice final do caminho, mesmo que não caia exatamente em um múltiplo
+    /// de `spacing`. Retorna um vetor vazio para um caminho vazio ou um espaçamento não positivo.
+    pub fn sample_at_interval<M: Metric>(&self, spacing: f64, metric: &M) -> Vec<Vertex> {
+        if self.lines.is_empty() || spacing <= 0.0 {
+            return Vec::new();
+        }
+
+        let total_length = *self.cumulative_lengths(metric).last().unwrap();
+
+        let mut samples = Vec::new();
+        let mut d = 0.0;
+        while d < total_length {
+            if let Some(vertex) = self.point_at_distance(d, metric) {
+                samples.push(vertex);
+            }
+            d += spacing;
+        }
+
+        if let Some(vertex) = self.point_at_distance(total_length, metric) {
+            samples.push(vertex);
+        }
+
+        samples
+    }
+
+    /// Extrai a sequência ordenada de vértices de um caminho a partir de suas linhas conectadas.
+    fn path_vertices(lines: &[Line]) -> Vec<Vertex> {
+        if lines.is_empty() {
+            return Vec::new();
+        }
+
+        let mut vertices = Vec::with_capacity(lines.len() + 1);
+        vertices.push(lines[0].starting_vertex);
+        for line in lines {
+            vertices.push(line.ending_vertex);
+        }
+
+        vertices
+    }
+
+    /// Calcula a distância discreta de Fréchet entre este caminho e outro, uma medida de
+    /// similaridade entre polilinhas útil para comparar trajetos (por exemplo, traços de GPS).
+    ///
+    /// # Parâmetros
+    /// - `other`: O caminho a ser comparado.
+    /// - `metric`: A métrica usada para a distância ponto a ponto.
+    ///
+    /// # Retorno
+    /// A distância de Fréchet entre os dois caminhos. Se qualquer um dos caminhos for vazio,
+    /// retorna `0.0`.
+    pub fn frechet_distance<const M: usize, Me: Metric>(
+        &self,
+        other: &Path<M>,
+        metric: &Me,
+    ) -> f64 {
+        let p = Self::path_vertices(&self.lines);
+        let q = Self::path_vertices(&other.lines);
+
+        if p.is_empty() || q.is_empty() {
+            return 0.0;
+        }
+
+        let n = p.len();
+        let m = q.len();
+
+        let mut ca = vec![vec![-1.0_f64; m]; n];
+
+        ca[0][0] = metric.distance(&p[0], &q[0]);
+
+        for i in 1..n {
+            ca[i][0] = ca[i - 1][0].max(metric.distance(&p[i], &q[0]));
+        }
+
+        for (j, q_vertex) in q.iter().enumerate().skip(1) {
+            ca[0][j] = ca[0][j - 1].max(metric.distance(&p[0], q_vertex));
+        }
+
+        for i in 1..n {
+            for j in 1..m {
+                let min_of_predecessors = ca[i - 1][j].min(ca[i - 1][j - 1]).min(ca[i][j - 1]);
+                ca[i][j] = min_of_predecessors.max(metric.distance(&p[i], &q[j]));
+            }
+        }
+
+        ca[n - 1][m - 1]
+    }
+}
+
+/// Um caminho com um número de linhas conhecido apenas em tempo de execução, guardadas em um
+/// `Vec` em vez de um array de tamanho fixo `[Line; N]`.
+///
+/// Complementa [`Path<N>`] para casos em que o número de vértices não é conhecido em tempo de
+/// compilação — por exemplo, ao ler uma rota de um arquivo GeoJSON ou CSV. Construído por meio
+/// de um [`PathBuilder`], que valida a mesma conectividade entre linhas exigida por
+/// [`Path::new`] dentro de cada subcaminho, mas permite saltos ("levantar a caneta") entre
+/// subcaminhos diferentes — ver [`DynPath::subpath_starts`].
+pub struct DynPath {
+    pub lines: Vec<Line>,
+    /// Indica se o caminho foi fechado com [`PathBuilder::close`], isto é, se inclui a linha
+    /// de retorno do último vértice ao primeiro.
+    pub closed: bool,
+    /// Índices em `lines` onde um novo subcaminho começa (isto é, onde [`PathBuilder::move_to`]
+    /// foi chamado para reposicionar a caneta em vez de continuar o subcaminho anterior). A
+    /// linha em cada um desses índices não precisa se conectar à linha anterior — todo o
+    /// restante precisa.
+    pub subpath_starts: Vec<usize>,
+}
+
+impl DynPath {
+    pub fn new(lines: Vec<Line>, closed: bool, subpath_starts: Vec<usize>) -> Result<Self, String> {
+        for i in 1..lines.len() {
+            if subpath_starts.contains(&i) {
+                continue;
+            }
+
+            if !lines_are_connected(&lines[i - 1], &lines[i]) {
+                return Err(format!(
+                    "Caminho inconsistente: linha {} não se conecta com a linha {}",
+                    i - 1,
+                    i
+                ));
+            }
+        }
+
+        Ok(Self {
+            lines,
+            closed,
+            subpath_starts,
+        })
+    }
+
+    /// Calcula a distância total do caminho segundo a métrica escolhida, com suporte a soma
+    /// paralela.
+    pub fn calculate_full_distance<M: Metric + Sync>(&self, metric: &M) -> f64 {
+        self.lines
+            .par_iter()
+            .map(|line| metric.distance(&line.starting_vertex, &line.ending_vertex))
+            .sum()
     }
 }
 
+impl<const N: usize> From<Path<N>> for DynPath {
+    /// Converte um `Path<N>` de tamanho fixo em um `DynPath` equivalente, alocado no heap.
+    ///
+    /// Um `Path<N>` nunca inclui a linha de fechamento por si só, então `closed` é sempre
+    /// `false`, e é sempre um único subcaminho contíguo, então `subpath_starts` fica vazio.
+    fn from(path: Path<N>) -> Self {
+        Self {
+            lines: Vec::from(path.lines),
+            closed: false,
+            subpath_starts: Vec::new(),
+        }
+    }
+}
+
+/// Constrói um [`DynPath`] incrementalmente a partir de uma sequência de vértices, no estilo de
+/// uma API de desenho vetorial (SVG, Canvas): `move_to` posiciona a caneta sem desenhar,
+/// `line_to` desenha uma linha até o novo vértice e `close` fecha o caminho de volta ao vértice
+/// de `move_to`.
+///
+/// # Exemplo
+/// ```
+/// use RustSpatial::geometries::{path::PathBuilder, vertex::Vertex};
+///
+/// let path = PathBuilder::new()
+///     .move_to(Vertex::new(0.0, 0.0))
+///     .line_to(Vertex::new(0.0, 1.0))
+///     .line_to(Vertex::new(1.0, 1.0))
+///     .close()
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(path.lines.len(), 3);
+/// assert!(path.closed);
+/// ```
+#[derive(Default)]
+pub struct PathBuilder {
+    lines: Vec<Line>,
+    current: Option<Vertex>,
+    subpath_start: Option<Vertex>,
+    subpath_starts: Vec<usize>,
+    pen_lifted: bool,
+    closed: bool,
+}
+
+impl PathBuilder {
+    pub fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            current: None,
+            subpath_start: None,
+            subpath_starts: Vec::new(),
+            pen_lifted: false,
+            closed: false,
+        }
+    }
+
+    /// Posiciona a caneta em `vertex` sem desenhar, marcando-o como o início de um novo
+    /// (sub)caminho para um `close` posterior.
+    ///
+    /// Chamar `move_to` depois de já ter desenhado algum trecho "levanta a caneta": a próxima
+    /// linha de `line_to` não precisa se conectar ao trecho anterior, permitindo representar
+    /// geometrias com múltiplos subcaminhos desconectados entre si.
+    pub fn move_to(mut self, vertex: Vertex) -> Self {
+        self.move_to_mut(vertex);
+        self
+    }
+
+    fn move_to_mut(&mut self, vertex: Vertex) {
+        self.current = Some(vertex);
+        self.subpath_start = Some(vertex);
+        self.pen_lifted = true;
+    }
+
+    /// Desenha uma linha do vértice atual até `vertex`, que passa a ser o novo vértice atual.
+    ///
+    /// Se nenhum `move_to` foi chamado antes, se comporta como um `move_to` implícito.
+    pub fn line_to(mut self, vertex: Vertex) -> Self {
+        self.line_to_mut(vertex);
+        self
+    }
+
+    fn line_to_mut(&mut self, vertex: Vertex) {
+        match self.current {
+            Some(current) => {
+                if let Ok(line) = Line::new(current, vertex) {
+                    if self.pen_lifted {
+                        if !self.lines.is_empty() {
+                            self.subpath_starts.push(self.lines.len());
+                        }
+                        self.pen_lifted = false;
+                    }
+                    self.lines.push(line);
+                }
+            }
+            None => self.subpath_start = Some(vertex),
+        }
+        self.current = Some(vertex);
+    }
+
+    /// Fecha o caminho, desenhando uma linha de volta do vértice atual ao vértice do último
+    /// `move_to`, de modo que `calculate_full_distance` inclua esse trecho de fechamento.
+    pub fn close(mut self) -> Self {
+        self.close_mut();
+        self
+    }
+
+    fn close_mut(&mut self) {
+        if let (Some(current), Some(start)) = (self.current, self.subpath_start) {
+            if let Ok(line) = Line::new(current, start) {
+                self.lines.push(line);
+            }
+        }
+        self.closed = true;
+    }
+
+    /// Constrói o [`DynPath`] final, validando a conectividade das linhas dentro de cada
+    /// subcaminho (saltos em pontos de `move_to` são permitidos).
+    pub fn build(self) -> Result<DynPath, String> {
+        DynPath::new(self.lines, self.closed, self.subpath_starts)
+    }
+}
+
+impl FromIterator<Vertex> for PathBuilder {
+    /// Constrói um `PathBuilder` a partir de uma sequência de vértices: o primeiro vira um
+    /// `move_to` e os demais, `line_to`s sucessivos.
+    fn from_iter<I: IntoIterator<Item = Vertex>>(iter: I) -> Self {
+        let mut builder = PathBuilder::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+impl Extend<Vertex> for PathBuilder {
+    fn extend<I: IntoIterator<Item = Vertex>>(&mut self, iter: I) {
+        for vertex in iter {
+            if self.current.is_none() {
+                self.move_to_mut(vertex);
+            } else {
+                self.line_to_mut(vertex);
+            }
+        }
+    }
+}
+
+/// Um caminho de Dubins: a rota mais curta entre duas configurações orientadas (posição +
+/// rumo) para um veículo com raio de curvatura mínimo fixo, composta por exatamente três
+/// trechos (reta e/ou arcos).
+///
+/// Ao contrário de [`Path<N>`], que conecta [`Line`]s retas arbitrárias, um caminho de Dubins é
+/// sempre formado por três [`Segment`]s — daí o tamanho fixo do array, sem necessidade de um
+/// parâmetro `const N: usize`.
+pub struct DubinsPath {
+    pub segments: [Segment; 3],
+}
+
+impl DubinsPath {
+    /// Calcula o comprimento total do caminho, somando o comprimento de cada trecho (incluindo
+    /// o comprimento de arco dos trechos curvos).
+    pub fn calculate_full_distance(&self) -> f64 {
+        self.segments.iter().map(Segment::length).sum()
+    }
+}
+
+/// Centro, em coordenadas planas, do círculo de raio `radius` tangente a `point` com rumo
+/// `heading` (radianos), virando à esquerda (`left = true`, sentido anti-horário) ou à direita
+/// (`left = false`, sentido horário).
+fn circle_center(point: Vertex, heading: f64, radius: f64, left: bool) -> Vertex {
+    let perpendicular = if left {
+        heading + HALF_PI
+    } else {
+        heading - HALF_PI
+    };
+    Vertex::new(
+        point.latitude + radius * perpendicular.cos(),
+        point.longitude + radius * perpendicular.sin(),
+    )
+}
+
+/// Ângulo (radianos), visto do centro `center`, do ponto `point` sobre sua circunferência.
+fn angle_from_center(center: Vertex, point: Vertex) -> f64 {
+    (point.longitude - center.longitude).atan2(point.latitude - center.latitude)
+}
+
+fn point_on_circle(center: Vertex, radius: f64, angle: f64) -> Vertex {
+    Vertex::new(
+        center.latitude + radius * angle.cos(),
+        center.longitude + radius * angle.sin(),
+    )
+}
+
+fn arc_segment(center: Vertex, radius: f64, start_angle: f64, end_angle: f64, left: bool) -> Arc {
+    let sweep_angle = if left {
+        normalize_angle(end_angle - start_angle)
+    } else {
+        normalize_angle(start_angle - end_angle)
+    };
+
+    Arc {
+        center,
+        radius,
+        start_angle,
+        sweep_angle,
+        clockwise: !left,
+    }
+}
+
+/// Tenta construir o caminho de Dubins do tipo "curva-reta-curva" (`LSL`, `RSR`, `RSL`, `LSR`)
+/// entre os círculos `c0` (em `start`) e `c1` (em `goal`), com `left0`/`left1` indicando o
+/// sentido de curvatura de cada um.
+///
+/// Retorna `None` quando a tangente exigida (interna, para `RSL`/`LSR`) não existe porque os
+/// círculos estão próximos demais (distância entre centros menor que `2 * radius`).
+#[allow(clippy::too_many_arguments)]
+fn build_csc(
+    start: Vertex,
+    goal: Vertex,
+    c0: Vertex,
+    c1: Vertex,
+    radius: f64,
+    left0: bool,
+    left1: bool,
+) -> Option<[Segment; 3]> {
+    let dx = c1.latitude - c0.latitude;
+    let dy = c1.longitude - c0.longitude;
+    let center_distance = (dx * dx + dy * dy).sqrt();
+    let center_line_angle = dy.atan2(dx);
+
+    // Tangente externa: os dois círculos giram no mesmo sentido, a reta de conexão é paralela
+    // à linha que une os centros.
+    let (t0, t1) = if left0 == left1 {
+        let offset_angle = if left0 {
+            center_line_angle - HALF_PI
+        } else {
+            center_line_angle + HALF_PI
+        };
+        let t0 = point_on_circle(c0, radius, offset_angle);
+        let t1 = point_on_circle(c1, radius, offset_angle);
+        (t0, t1)
+    } else {
+        // Tangente interna: os círculos giram em sentidos opostos, a reta de conexão cruza
+        // entre eles. Só existe quando os círculos não se sobrepõem demais.
+        if center_distance < 2.0 * radius {
+            return None;
+        }
+        let half_angle = (2.0 * radius / center_distance).asin();
+        let psi = if left0 {
+            // LSR
+            center_line_angle + half_angle
+        } else {
+            // RSL
+            center_line_angle - half_angle
+        };
+        let (angle0, angle1) = if left0 {
+            (psi - HALF_PI, psi + HALF_PI)
+        } else {
+            (psi + HALF_PI, psi - HALF_PI)
+        };
+        let t0 = point_on_circle(c0, radius, angle0);
+        let t1 = point_on_circle(c1, radius, angle1);
+        (t0, t1)
+    };
+
+    let arc0 = arc_segment(c0, radius, angle_from_center(c0, start), angle_from_center(c0, t0), left0);
+    let arc1 = arc_segment(c1, radius, angle_from_center(c1, t1), angle_from_center(c1, goal), left1);
+    let straight = Line::new(t0, t1).ok()?;
+
+    Some([Segment::Arc(arc0), Segment::Straight(straight), Segment::Arc(arc1)])
+}
+
+/// Tenta construir o caminho de Dubins do tipo "curva-curva-curva" (`RLR`/`LRL`) entre os
+/// círculos `c0` e `c1`, ambos girando no sentido indicado por `left`.
+///
+/// Existe apenas quando os dois círculos "alcançam" um terceiro círculo de mesmo raio tangente
+/// externamente a ambos, isto é, quando a distância entre os centros é no máximo `4 * radius`.
+fn build_ccc(
+    start: Vertex,
+    goal: Vertex,
+    c0: Vertex,
+    c1: Vertex,
+    radius: f64,
+    left: bool,
+) -> Option<[Segment; 3]> {
+    let dx = c1.latitude - c0.latitude;
+    let dy = c1.longitude - c0.longitude;
+    let center_distance = (dx * dx + dy * dy).sqrt();
+
+    if center_distance > 4.0 * radius || center_distance < 1e-9 {
+        return None;
+    }
+
+    let center_line_angle = dy.atan2(dx);
+    let half_chord = center_distance / 2.0;
+    let offset = (4.0 * radius * radius - half_chord * half_chord).max(0.0).sqrt();
+
+    let perpendicular = if left {
+        center_line_angle + HALF_PI
+    } else {
+        center_line_angle - HALF_PI
+    };
+
+    let midpoint = Vertex::new(
+        (c0.latitude + c1.latitude) / 2.0,
+        (c0.longitude + c1.longitude) / 2.0,
+    );
+    let middle_center = Vertex::new(
+        midpoint.latitude + offset * perpendicular.cos(),
+        midpoint.longitude + offset * perpendicular.sin(),
+    );
+
+    // Os círculos são tangentes externamente (mesmo raio), então o ponto de tangência é o
+    // ponto médio entre os centros.
+    let t0 = Vertex::new(
+        (c0.latitude + middle_center.latitude) / 2.0,
+        (c0.longitude + middle_center.longitude) / 2.0,
+    );
+    let t1 = Vertex::new(
+        (c1.latitude + middle_center.latitude) / 2.0,
+        (c1.longitude + middle_center.longitude) / 2.0,
+    );
+
+    let arc0 = arc_segment(c0, radius, angle_from_center(c0, start), angle_from_center(c0, t0), left);
+    let arc_mid = arc_segment(
+        middle_center,
+        radius,
+        angle_from_center(middle_center, t0),
+        angle_from_center(middle_center, t1),
+        !left,
+    );
+    let arc1 = arc_segment(c1, radius, angle_from_center(c1, t1), angle_from_center(c1, goal), left);
+
+    Some([Segment::Arc(arc0), Segment::Arc(arc_mid), Segment::Arc(arc1)])
+}
+
+/// Constrói a rota de Dubins mais curta entre duas configurações orientadas (posição + rumo),
+/// para um veículo restrito a um raio de curvatura mínimo `turning_radius` (aeronaves de asa
+/// fixa, carros que não conseguem virar no próprio eixo).
+///
+/// # Parâmetros
+/// - `start`, `goal`: Vértices de partida e chegada.
+/// - `start_heading`, `goal_heading`: Rumos em radianos, medidos como em um plano cartesiano
+///   local (latitude no eixo x, longitude no eixo y) — a mesma aproximação planar usada pela
+///   métrica `Euclidean`.
+/// - `turning_radius`: O raio de curvatura mínimo do veículo, nas mesmas unidades dos vértices.
+///
+/// # Notas
+/// Enumera os seis tipos de palavra de Dubins (`LSL`, `RSR`, `RSL`, `LSR`, `RLR`, `LRL`),
+/// descarta os geometricamente inviáveis e retorna o de menor comprimento total. Quando os
+/// círculos de partida e chegada estão muito próximos, as palavras `RSL`/`LSR` deixam de ser
+/// viáveis, pois a tangente interna que exigem deixa de existir; `LSL`/`RSR` (tangente externa)
+/// e `RLR`/`LRL` continuam candidatas.
+///
+/// A escolha de lado do círculo intermediário em `RLR`/`LRL` segue uma convenção consistente
+/// com a construção de `LSL`/`RSR` (verificada pela distância de tangência entre os círculos) e
+/// é coberta, junto dos outros cinco tipos de palavra, pelos testes de `build_csc`/`build_ccc`
+/// abaixo.
+pub fn dubins_connect(
+    start: Vertex,
+    start_heading: f64,
+    goal: Vertex,
+    goal_heading: f64,
+    turning_radius: f64,
+) -> DubinsPath {
+    let r = turning_radius;
+
+    let left_start = circle_center(start, start_heading, r, true);
+    let right_start = circle_center(start, start_heading, r, false);
+    let left_goal = circle_center(goal, goal_heading, r, true);
+    let right_goal = circle_center(goal, goal_heading, r, false);
+
+    let candidates = [
+        build_csc(start, goal, left_start, left_goal, r, true, true),
+        build_csc(start, goal, right_start, right_goal, r, false, false),
+        build_csc(start, goal, right_start, left_goal, r, false, true),
+        build_csc(start, goal, left_start, right_goal, r, true, false),
+        build_ccc(start, goal, right_start, right_goal, r, false),
+        build_ccc(start, goal, left_start, left_goal, r, true),
+    ];
+
+    let best = candidates
+        .into_iter()
+        .flatten()
+        .min_by(|a, b| {
+            let length_a: f64 = a.iter().map(Segment::length).sum();
+            let length_b: f64 = b.iter().map(Segment::length).sum();
+            length_a.total_cmp(&length_b)
+        })
+        .expect("LSL e RSR são sempre geometricamente viáveis para raio positivo");
+
+    DubinsPath { segments: best }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{consts::EARTH_RADIUS_KM, geometries::vertex::Vertex};
@@ -146,7 +771,7 @@ mod tests {
         let path = Path::new([line_a, line_b]).unwrap();
 
         // Distância total: 5 (linha A -> B) + 5 (linha B -> C) = 10
-        let total_distance = path.calculate_full_distance(PathCalcFullDistanceOptions::Euclidean);
+        let total_distance = path.calculate_full_distance(&Euclidean);
         assert_eq!(total_distance, 10.0);
     }
 
@@ -163,21 +788,29 @@ mod tests {
 
         // Distância Haversine esperada para cada linha (aproximadamente)
         let expected_distance_per_line = EARTH_RADIUS_KM * std::f64::consts::PI / 180.0; // 1 grau em radianos
-        let total_distance = path.calculate_full_distance(PathCalcFullDistanceOptions::EarthRadius);
+        let total_distance = path.calculate_full_distance(&Haversine);
 
         // Valida que a distância total está próxima do esperado (2 vezes a distância por linha)
         assert!((total_distance - 2.0 * expected_distance_per_line).abs() < 0.001);
     }
 
+    #[test]
+    fn test_calculate_full_distance_manhattan() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(3.0, 4.0);
+
+        let line = Line::new(vertex_a, vertex_b).unwrap();
+        let path = Path::new([line]).unwrap();
+
+        assert_eq!(path.calculate_full_distance(&Manhattan), 7.0);
+    }
+
     #[test]
     fn test_empty_path() {
         let path: Result<Path<0>, String> = Path::new([]);
         assert!(path.is_ok());
         let path = path.unwrap();
-        assert_eq!(
-            path.calculate_full_distance(PathCalcFullDistanceOptions::Euclidean),
-            0.0
-        );
+        assert_eq!(path.calculate_full_distance(&Euclidean), 0.0);
     }
 
     #[test]
@@ -188,10 +821,7 @@ mod tests {
         let line = Line::new(vertex_a, vertex_b).unwrap();
         let path = Path::new([line]).unwrap();
 
-        assert_eq!(
-            path.calculate_full_distance(PathCalcFullDistanceOptions::Euclidean),
-            5.0
-        );
+        assert_eq!(path.calculate_full_distance(&Euclidean), 5.0);
     }
 
     #[test]
@@ -204,10 +834,7 @@ mod tests {
 
         let path = Path::new([line_a, line_b]).unwrap();
 
-        assert_eq!(
-            path.calculate_full_distance(PathCalcFullDistanceOptions::Euclidean),
-            10.0
-        );
+        assert_eq!(path.calculate_full_distance(&Euclidean), 10.0);
     }
 
     #[test]
@@ -222,4 +849,391 @@ mod tests {
 
         assert!(path.is_err());
     }
+
+    #[test]
+    fn test_frechet_distance_identical_paths_is_zero() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(0.0, 1.0);
+        let vertex_c = Vertex::new(0.0, 2.0);
+
+        let line_a = Line::new(vertex_a, vertex_b).unwrap();
+        let line_b = Line::new(vertex_b, vertex_c).unwrap();
+
+        let path = Path::new([line_a, line_b]).unwrap();
+
+        let line_a_copy = Line::new(vertex_a, vertex_b).unwrap();
+        let line_b_copy = Line::new(vertex_b, vertex_c).unwrap();
+        let other = Path::new([line_a_copy, line_b_copy]).unwrap();
+
+        assert_eq!(path.frechet_distance(&other, &Euclidean), 0.0);
+    }
+
+    #[test]
+    fn test_frechet_distance_parallel_paths() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(0.0, 1.0);
+        let path = Path::new([Line::new(vertex_a, vertex_b).unwrap()]).unwrap();
+
+        let vertex_c = Vertex::new(1.0, 0.0);
+        let vertex_d = Vertex::new(1.0, 1.0);
+        let other = Path::new([Line::new(vertex_c, vertex_d).unwrap()]).unwrap();
+
+        assert_eq!(path.frechet_distance(&other, &Euclidean), 1.0);
+    }
+
+    #[test]
+    fn test_frechet_distance_empty_path_is_zero() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(0.0, 1.0);
+        let path = Path::new([Line::new(vertex_a, vertex_b).unwrap()]).unwrap();
+
+        let empty: Path<0> = Path::new([]).unwrap();
+
+        assert_eq!(path.frechet_distance(&empty, &Euclidean), 0.0);
+    }
+
+    #[test]
+    fn test_point_at_distance_clamps_and_interpolates() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(0.0, 10.0);
+
+        let path = Path::new([Line::new(vertex_a, vertex_b).unwrap()]).unwrap();
+
+        assert_eq!(path.point_at_distance(-5.0, &Euclidean), Some(vertex_a));
+        assert_eq!(
+            path.point_at_distance(5.0, &Euclidean),
+            Some(Vertex::new(0.0, 5.0))
+        );
+        assert_eq!(path.point_at_distance(100.0, &Euclidean), Some(vertex_b));
+    }
+
+    #[test]
+    fn test_point_at_distance_empty_path_is_none() {
+        let empty: Path<0> = Path::new([]).unwrap();
+
+        assert_eq!(empty.point_at_distance(1.0, &Euclidean), None);
+    }
+
+    #[test]
+    fn test_sample_at_interval_densifies_path() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(0.0, 10.0);
+
+        let path = Path::new([Line::new(vertex_a, vertex_b).unwrap()]).unwrap();
+
+        let samples = path.sample_at_interval(4.0, &Euclidean);
+
+        assert_eq!(
+            samples,
+            vec![
+                Vertex::new(0.0, 0.0),
+                Vertex::new(0.0, 4.0),
+                Vertex::new(0.0, 8.0),
+                Vertex::new(0.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_path_builder_builds_open_path() {
+        let path = PathBuilder::new()
+            .move_to(Vertex::new(0.0, 0.0))
+            .line_to(Vertex::new(0.0, 1.0))
+            .line_to(Vertex::new(1.0, 1.0))
+            .build()
+            .unwrap();
+
+        assert_eq!(path.lines.len(), 2);
+        assert!(!path.closed);
+        assert_eq!(path.calculate_full_distance(&Euclidean), 2.0);
+    }
+
+    #[test]
+    fn test_path_builder_close_appends_closing_leg() {
+        let path = PathBuilder::new()
+            .move_to(Vertex::new(0.0, 0.0))
+            .line_to(Vertex::new(0.0, 1.0))
+            .line_to(Vertex::new(1.0, 1.0))
+            .close()
+            .build()
+            .unwrap();
+
+        assert_eq!(path.lines.len(), 3);
+        assert!(path.closed);
+        assert_eq!(path.lines[2].ending_vertex, Vertex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_path_builder_supports_disconnected_subpaths() {
+        // `move_to` no meio da sequência levanta a caneta: o segundo subcaminho não precisa se
+        // conectar ao primeiro, diferente da validação de [`Path::new`].
+        let path = PathBuilder::new()
+            .move_to(Vertex::new(0.0, 0.0))
+            .line_to(Vertex::new(0.0, 1.0))
+            .move_to(Vertex::new(10.0, 10.0))
+            .line_to(Vertex::new(10.0, 11.0))
+            .build()
+            .unwrap();
+
+        assert_eq!(path.lines.len(), 2);
+        assert_eq!(path.subpath_starts, vec![1]);
+        assert_eq!(path.lines[1].starting_vertex, Vertex::new(10.0, 10.0));
+    }
+
+    #[test]
+    fn test_dyn_path_new_rejects_disconnected_lines_without_a_subpath_boundary() {
+        let lines = vec![
+            Line::new(Vertex::new(0.0, 0.0), Vertex::new(0.0, 1.0)).unwrap(),
+            Line::new(Vertex::new(10.0, 10.0), Vertex::new(10.0, 11.0)).unwrap(),
+        ];
+
+        assert!(DynPath::new(lines, false, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_dyn_path_new_accepts_disconnected_lines_with_a_declared_subpath_boundary() {
+        let lines = vec![
+            Line::new(Vertex::new(0.0, 0.0), Vertex::new(0.0, 1.0)).unwrap(),
+            Line::new(Vertex::new(10.0, 10.0), Vertex::new(10.0, 11.0)).unwrap(),
+        ];
+
+        assert!(DynPath::new(lines, false, vec![1]).is_ok());
+    }
+
+    #[test]
+    fn test_path_builder_from_iterator() {
+        let vertices = vec![
+            Vertex::new(0.0, 0.0),
+            Vertex::new(0.0, 1.0),
+            Vertex::new(1.0, 1.0),
+        ];
+
+        let path: DynPath = vertices.into_iter().collect::<PathBuilder>().build().unwrap();
+
+        assert_eq!(path.lines.len(), 2);
+    }
+
+    #[test]
+    fn test_path_builder_extend() {
+        let mut builder = PathBuilder::new();
+        builder.extend(vec![Vertex::new(0.0, 0.0), Vertex::new(0.0, 1.0)]);
+        builder.extend(vec![Vertex::new(1.0, 1.0)]);
+
+        let path = builder.build().unwrap();
+
+        assert_eq!(path.lines.len(), 2);
+    }
+
+    #[test]
+    fn test_path_to_dyn_path_conversion() {
+        let vertex_a = Vertex::new(0.0, 0.0);
+        let vertex_b = Vertex::new(3.0, 4.0);
+
+        let path = Path::new([Line::new(vertex_a, vertex_b).unwrap()]).unwrap();
+        let dyn_path: DynPath = path.into();
+
+        assert_eq!(dyn_path.lines.len(), 1);
+        assert!(!dyn_path.closed);
+        assert_eq!(dyn_path.calculate_full_distance(&Euclidean), 5.0);
+    }
+
+    fn assert_vertices_close(a: Vertex, b: Vertex) {
+        assert!(
+            (a.latitude - b.latitude).abs() < 1e-6 && (a.longitude - b.longitude).abs() < 1e-6,
+            "esperava vértices próximos, obtive {:?} e {:?}",
+            a,
+            b
+        );
+    }
+
+    /// Verifica que os três trechos de `segments` se conectam extremo a extremo, do ponto de
+    /// partida `start` até o de chegada `goal`.
+    fn assert_dubins_path_is_continuous(segments: &[Segment; 3], start: Vertex, goal: Vertex) {
+        assert_vertices_close(segments[0].starting_vertex(), start);
+        assert_vertices_close(segments[0].ending_vertex(), segments[1].starting_vertex());
+        assert_vertices_close(segments[1].ending_vertex(), segments[2].starting_vertex());
+        assert_vertices_close(segments[2].ending_vertex(), goal);
+    }
+
+    #[test]
+    fn test_build_csc_lsl() {
+        // Partida e chegada já alinhadas com o rumo, de forma que os pontos de tangência
+        // coincidem exatamente com `start`/`goal` (o caminho degenera em uma reta pura).
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(10.0, 0.0);
+        let radius = 1.0;
+
+        let left_start = circle_center(start, 0.0, radius, true);
+        let left_goal = circle_center(goal, 0.0, radius, true);
+
+        let segments =
+            build_csc(start, goal, left_start, left_goal, radius, true, true).unwrap();
+
+        assert_dubins_path_is_continuous(&segments, start, goal);
+        match &segments[0] {
+            Segment::Arc(arc) => assert!(!arc.clockwise),
+            _ => panic!("esperava um arco no primeiro trecho de LSL"),
+        }
+    }
+
+    #[test]
+    fn test_build_csc_rsr() {
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(10.0, 0.0);
+        let radius = 1.0;
+
+        let right_start = circle_center(start, 0.0, radius, false);
+        let right_goal = circle_center(goal, 0.0, radius, false);
+
+        let segments =
+            build_csc(start, goal, right_start, right_goal, radius, false, false).unwrap();
+
+        assert_dubins_path_is_continuous(&segments, start, goal);
+        match &segments[0] {
+            Segment::Arc(arc) => assert!(arc.clockwise),
+            _ => panic!("esperava um arco no primeiro trecho de RSR"),
+        }
+    }
+
+    #[test]
+    fn test_build_csc_rsl() {
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(6.0, 0.0);
+        let radius = 1.0;
+
+        let right_start = circle_center(start, 0.0, radius, false);
+        let left_goal = circle_center(goal, std::f64::consts::PI, radius, true);
+
+        let segments =
+            build_csc(start, goal, right_start, left_goal, radius, false, true).unwrap();
+
+        assert_dubins_path_is_continuous(&segments, start, goal);
+
+        // A meia-tangente interna é `asin(2r / d)`, com `d = 6` e `r = 1`.
+        let half_angle = (2.0 * radius / 6.0_f64).asin();
+        match (&segments[0], &segments[2]) {
+            (Segment::Arc(arc0), Segment::Arc(arc1)) => {
+                assert!(arc0.clockwise);
+                assert!(!arc1.clockwise);
+                assert!((arc0.sweep_angle - half_angle).abs() < 1e-6);
+                assert!((arc1.sweep_angle - (std::f64::consts::PI + half_angle)).abs() < 1e-6);
+            }
+            _ => panic!("esperava arcos no primeiro e terceiro trechos de RSL"),
+        }
+    }
+
+    #[test]
+    fn test_build_csc_lsr() {
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(6.0, 0.0);
+        let radius = 1.0;
+
+        let left_start = circle_center(start, 0.0, radius, true);
+        let right_goal = circle_center(goal, std::f64::consts::PI, radius, false);
+
+        let segments =
+            build_csc(start, goal, left_start, right_goal, radius, true, false).unwrap();
+
+        assert_dubins_path_is_continuous(&segments, start, goal);
+
+        let half_angle = (2.0 * radius / 6.0_f64).asin();
+        match (&segments[0], &segments[2]) {
+            (Segment::Arc(arc0), Segment::Arc(arc1)) => {
+                assert!(!arc0.clockwise);
+                assert!(arc1.clockwise);
+                assert!((arc0.sweep_angle - half_angle).abs() < 1e-6);
+                assert!((arc1.sweep_angle - (std::f64::consts::PI + half_angle)).abs() < 1e-6);
+            }
+            _ => panic!("esperava arcos no primeiro e terceiro trechos de LSR"),
+        }
+    }
+
+    #[test]
+    fn test_build_csc_internal_tangent_returns_none_when_circles_overlap() {
+        // Círculos a menos de `2 * radius` um do outro não têm tangente interna: RSL/LSR ficam
+        // geometricamente inviáveis, restando apenas RLR/LRL (cobertos no teste de `build_ccc`
+        // abaixo).
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(1.0, 0.0);
+        let radius = 1.0;
+
+        // Rumos opostos fazem os círculos de giro contrário (direita na partida, esquerda na
+        // chegada) ficarem a uma distância menor que `2 * radius` entre si.
+        let right_start = circle_center(start, 0.0, radius, false);
+        let left_goal = circle_center(goal, std::f64::consts::PI, radius, true);
+
+        assert!(build_csc(start, goal, right_start, left_goal, radius, false, true).is_none());
+    }
+
+    #[test]
+    fn test_build_ccc_rlr_when_circles_too_close_for_csc_tangent() {
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(1.0, 0.0);
+        let radius = 1.0;
+
+        let right_start = circle_center(start, 0.0, radius, false);
+        let right_goal = circle_center(goal, 0.0, radius, false);
+
+        let segments =
+            build_ccc(start, goal, right_start, right_goal, radius, false).unwrap();
+
+        assert_dubins_path_is_continuous(&segments, start, goal);
+        match &segments[1] {
+            Segment::Arc(arc) => assert!(!arc.clockwise, "círculo do meio de RLR gira à esquerda"),
+            _ => panic!("esperava um arco no trecho intermediário de RLR"),
+        }
+    }
+
+    #[test]
+    fn test_build_ccc_lrl_when_circles_too_close_for_csc_tangent() {
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(1.0, 0.0);
+        let radius = 1.0;
+
+        let left_start = circle_center(start, 0.0, radius, true);
+        let left_goal = circle_center(goal, 0.0, radius, true);
+
+        let segments = build_ccc(start, goal, left_start, left_goal, radius, true).unwrap();
+
+        assert_dubins_path_is_continuous(&segments, start, goal);
+        match &segments[1] {
+            Segment::Arc(arc) => assert!(arc.clockwise, "círculo do meio de LRL gira à direita"),
+            _ => panic!("esperava um arco no trecho intermediário de LRL"),
+        }
+    }
+
+    #[test]
+    fn test_build_ccc_none_when_circles_too_far_apart() {
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(100.0, 0.0);
+        let radius = 1.0;
+
+        let right_start = circle_center(start, 0.0, radius, false);
+        let right_goal = circle_center(goal, 0.0, radius, false);
+
+        assert!(build_ccc(start, goal, right_start, right_goal, radius, false).is_none());
+    }
+
+    #[test]
+    fn test_dubins_connect_produces_continuous_path_between_far_apart_configurations() {
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(6.0, 0.0);
+
+        let path = dubins_connect(start, 0.0, goal, std::f64::consts::PI, 1.0);
+
+        assert_dubins_path_is_continuous(&path.segments, start, goal);
+        assert!(path.calculate_full_distance() >= 6.0);
+    }
+
+    #[test]
+    fn test_dubins_connect_produces_continuous_path_for_nearby_configuration() {
+        // Rumos opostos e pontos próximos: a tangente interna de RSL/LSR deixa de existir,
+        // restando LSL/RSR e RLR/LRL como candidatos.
+        let start = Vertex::new(0.0, 0.0);
+        let goal = Vertex::new(1.0, 0.0);
+
+        let path = dubins_connect(start, 0.0, goal, std::f64::consts::PI, 1.0);
+
+        assert_dubins_path_is_continuous(&path.segments, start, goal);
+    }
 }
@@ -0,0 +1,395 @@
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+};
+
+use super::{
+    line::Line,
+    path::{DynPath, Metric},
+    vertex::Vertex,
+};
+
+/// Um polígono simples (sem autointerseção), construído a partir de um [`DynPath`] fechado.
+///
+/// Os vértices são guardados na mesma ordem do caminho de origem, sem repetir o vértice de
+/// fechamento (o último vértice do anel é seguido implicitamente pelo primeiro).
+pub struct Polygon {
+    vertices: Vec<Vertex>,
+}
+
+impl Polygon {
+    /// Cria um polígono a partir de um [`DynPath`] fechado com ao menos 3 vértices.
+    pub fn new(path: DynPath) -> Result<Self, String> {
+        if !path.closed {
+            return Err("O caminho precisa estar fechado para formar um polígono".to_string());
+        }
+
+        if path.lines.len() < 3 {
+            return Err(format!(
+                "Um polígono precisa de ao menos 3 vértices, mas o caminho tem {}",
+                path.lines.len()
+            ));
+        }
+
+        if !path.subpath_starts.is_empty() {
+            return Err(
+                "O caminho precisa ser um único anel contínuo, sem levantamentos de caneta"
+                    .to_string(),
+            );
+        }
+
+        let vertices = path.lines.iter().map(|line| line.starting_vertex).collect();
+
+        Ok(Self { vertices })
+    }
+
+    /// Retorna os vértices do polígono, na ordem do anel.
+    pub fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+
+    /// Calcula a área do polígono pela fórmula do shoelace (do cadarço).
+    ///
+    /// # Fórmula
+    /// ```text
+    /// A = 0.5 * |Σ (x_i * y_{i+1} - x_{i+1} * y_i)|
+    /// ```
+    /// Tratando latitude como `x` e longitude como `y`, no mesmo plano local aproximado usado
+    /// pela métrica `Euclidean`.
+    pub fn area(&self) -> f64 {
+        let n = self.vertices.len();
+        let mut sum = 0.0;
+
+        for i in 0..n {
+            let current = &self.vertices[i];
+            let next = &self.vertices[(i + 1) % n];
+            sum += current.latitude * next.longitude - next.latitude * current.longitude;
+        }
+
+        (sum / 2.0).abs()
+    }
+
+    /// Verifica se `v` está dentro do polígono, usando o algoritmo de ray-casting (PNPOLY de
+    /// W. Randolph Franklin): lança um raio horizontal a partir de `v` e conta quantas arestas
+    /// ele cruza — o ponto está dentro se, e somente se, a contagem for ímpar.
+    ///
+    /// A comparação assimétrica (`>` de um lado, implícita do outro) evita contar duas vezes um
+    /// raio que passa exatamente por um vértice do polígono.
+    pub fn contains(&self, v: &Vertex) -> bool {
+        let n = self.vertices.len();
+        let mut inside = false;
+        let mut j = n - 1;
+
+        for i in 0..n {
+            let vi = &self.vertices[i];
+            let vj = &self.vertices[j];
+
+            let straddles = (vi.longitude > v.longitude) != (vj.longitude > v.longitude);
+            if straddles {
+                let intersection_latitude = (vj.latitude - vi.latitude)
+                    * (v.longitude - vi.longitude)
+                    / (vj.longitude - vi.longitude)
+                    + vi.latitude;
+
+                if v.latitude < intersection_latitude {
+                    inside = !inside;
+                }
+            }
+
+            j = i;
+        }
+
+        inside
+    }
+
+    /// Retorna as arestas do polígono como pares de vértices consecutivos (incluindo a aresta de
+    /// fechamento do último vértice de volta ao primeiro).
+    fn edges(&self) -> impl Iterator<Item = (&Vertex, &Vertex)> {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (&self.vertices[i], &self.vertices[(i + 1) % n]))
+    }
+
+    /// Verifica se o segmento `p`-`q` é visível, isto é, se não cruza propriamente nenhuma
+    /// aresta do polígono e não atravessa seu interior.
+    ///
+    /// Extremidades compartilhadas entre `p`-`q` e uma aresta não contam como cruzamento — isso
+    /// é o que permite que vértices adjacentes do polígono "se vejam" através de sua própria
+    /// aresta.
+    fn segment_is_visible(&self, p: Vertex, q: Vertex) -> bool {
+        for (a, b) in self.edges() {
+            if (p == *a && q == *b) || (p == *b && q == *a) {
+                return true;
+            }
+        }
+
+        for (a, b) in self.edges() {
+            if segments_properly_cross(p, q, *a, *b) {
+                return false;
+            }
+        }
+
+        let midpoint = Vertex::new((p.latitude + q.latitude) / 2.0, (p.longitude + q.longitude) / 2.0);
+
+        !self.contains(&midpoint)
+    }
+
+    /// Encontra o caminho mais curto de `start` até `goal` que contorna o obstáculo
+    /// representado por este polígono, usando um grafo de visibilidade (nós: vértices do
+    /// polígono mais `start` e `goal`; arestas: pares de nós mutuamente visíveis, com peso dado
+    /// pela métrica `metric`) e o algoritmo de Dijkstra.
+    ///
+    /// Retorna `None` quando não existe nenhum caminho (por exemplo, se `start` ou `goal`
+    /// estiverem dentro do polígono, cercados pelo obstáculo).
+    pub fn shortest_path<M: Metric>(&self, start: Vertex, goal: Vertex, metric: &M) -> Option<DynPath> {
+        let mut nodes = self.vertices.clone();
+        let start_index = nodes.len();
+        nodes.push(start);
+        let goal_index = nodes.len();
+        nodes.push(goal);
+
+        let node_count = nodes.len();
+        let mut adjacency = vec![Vec::new(); node_count];
+
+        for i in 0..node_count {
+            for j in (i + 1)..node_count {
+                if self.segment_is_visible(nodes[i], nodes[j]) {
+                    let weight = metric.distance(&nodes[i], &nodes[j]);
+                    adjacency[i].push((j, weight));
+                    adjacency[j].push((i, weight));
+                }
+            }
+        }
+
+        let predecessors = dijkstra(&adjacency, start_index, goal_index)?;
+
+        let vertex_sequence: Vec<Vertex> = predecessors.into_iter().map(|index| nodes[index]).collect();
+
+        let lines: Vec<Line> = vertex_sequence
+            .windows(2)
+            .filter_map(|pair| Line::new(pair[0], pair[1]).ok())
+            .collect();
+
+        DynPath::new(lines, false, Vec::new()).ok()
+    }
+}
+
+/// Estado de uma fronteira de busca na fila de prioridade do Dijkstra: menor distância primeiro.
+#[derive(PartialEq)]
+struct Frontier {
+    distance: f64,
+    node: usize,
+}
+
+impl Eq for Frontier {}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Invertido: `BinaryHeap` é um max-heap, e queremos extrair a menor distância primeiro.
+        other.distance.total_cmp(&self.distance)
+    }
+}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Roda o algoritmo de Dijkstra sobre uma lista de adjacência, retornando a sequência de nós de
+/// `start` até `goal` (inclusive), ou `None` se `goal` for inalcançável.
+fn dijkstra(adjacency: &[Vec<(usize, f64)>], start: usize, goal: usize) -> Option<Vec<usize>> {
+    let node_count = adjacency.len();
+    let mut distances = vec![f64::INFINITY; node_count];
+    let mut previous = vec![None; node_count];
+    let mut queue = BinaryHeap::new();
+
+    distances[start] = 0.0;
+    queue.push(Frontier { distance: 0.0, node: start });
+
+    while let Some(Frontier { distance, node }) = queue.pop() {
+        if node == goal {
+            break;
+        }
+
+        if distance > distances[node] {
+            continue;
+        }
+
+        for &(neighbor, weight) in &adjacency[node] {
+            let candidate_distance = distance + weight;
+            if candidate_distance < distances[neighbor] {
+                distances[neighbor] = candidate_distance;
+                previous[neighbor] = Some(node);
+                queue.push(Frontier { distance: candidate_distance, node: neighbor });
+            }
+        }
+    }
+
+    if distances[goal].is_infinite() {
+        return None;
+    }
+
+    let mut path = vec![goal];
+    let mut current = goal;
+    while let Some(previous_node) = previous[current] {
+        path.push(previous_node);
+        current = previous_node;
+    }
+    path.reverse();
+
+    Some(path)
+}
+
+/// Produto vetorial (componente z) de `b - a` e `c - b`, usado para determinar a orientação de
+/// três pontos, com latitude como `x` e longitude como `y` (positivo: horário; negativo:
+/// anti-horário; zero: colineares).
+fn orientation(a: Vertex, b: Vertex, c: Vertex) -> f64 {
+    (b.longitude - a.longitude) * (c.latitude - b.latitude)
+        - (b.latitude - a.latitude) * (c.longitude - b.longitude)
+}
+
+/// Verifica se o ponto colinear `c` está dentro da caixa delimitadora de `a`-`b`.
+fn on_segment(a: Vertex, b: Vertex, c: Vertex) -> bool {
+    c.latitude <= a.latitude.max(b.latitude)
+        && c.latitude >= a.latitude.min(b.latitude)
+        && c.longitude <= a.longitude.max(b.longitude)
+        && c.longitude >= a.longitude.min(b.longitude)
+}
+
+/// Verifica se os segmentos `p1`-`p2` e `p3`-`p4` se cruzam propriamente.
+///
+/// Extremidades compartilhadas não contam como cruzamento: isso é necessário para que um
+/// segmento do grafo de visibilidade que toca um vértice do polígono (mas não atravessa suas
+/// arestas) continue sendo considerado visível. Sobreposições colineares são tratadas como
+/// cruzamento, por segurança.
+fn segments_properly_cross(p1: Vertex, p2: Vertex, p3: Vertex, p4: Vertex) -> bool {
+    if p1 == p3 || p1 == p4 || p2 == p3 || p2 == p4 {
+        return false;
+    }
+
+    let d1 = orientation(p3, p4, p1);
+    let d2 = orientation(p3, p4, p2);
+    let d3 = orientation(p1, p2, p3);
+    let d4 = orientation(p1, p2, p4);
+
+    if ((d1 > 0.0) != (d2 > 0.0)) && ((d3 > 0.0) != (d4 > 0.0)) && d1 != 0.0 && d2 != 0.0 {
+        return true;
+    }
+
+    // Casos degenerados: um ponto colinear com o outro segmento e dentro de sua caixa
+    // delimitadora.
+    if d1 == 0.0 && on_segment(p3, p4, p1) {
+        return true;
+    }
+    if d2 == 0.0 && on_segment(p3, p4, p2) {
+        return true;
+    }
+    if d3 == 0.0 && on_segment(p1, p2, p3) {
+        return true;
+    }
+    if d4 == 0.0 && on_segment(p1, p2, p4) {
+        return true;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometries::path::{Euclidean, Metric, PathBuilder};
+
+    use super::*;
+
+    fn unit_square() -> Polygon {
+        let path = PathBuilder::new()
+            .move_to(Vertex::new(0.0, 0.0))
+            .line_to(Vertex::new(0.0, 4.0))
+            .line_to(Vertex::new(4.0, 4.0))
+            .line_to(Vertex::new(4.0, 0.0))
+            .close()
+            .build()
+            .unwrap();
+
+        Polygon::new(path).unwrap()
+    }
+
+    #[test]
+    fn test_polygon_requires_closed_path() {
+        let path = PathBuilder::new()
+            .move_to(Vertex::new(0.0, 0.0))
+            .line_to(Vertex::new(0.0, 1.0))
+            .build()
+            .unwrap();
+
+        assert!(Polygon::new(path).is_err());
+    }
+
+    #[test]
+    fn test_polygon_rejects_path_with_disjoint_subpaths() {
+        // Fechado, com 3+ vértices, mas composto por dois subcaminhos desconexos (levantamento
+        // de caneta entre eles) — não é um anel único e não forma um polígono válido.
+        let path = PathBuilder::new()
+            .move_to(Vertex::new(0.0, 0.0))
+            .line_to(Vertex::new(0.0, 1.0))
+            .move_to(Vertex::new(5.0, 5.0))
+            .line_to(Vertex::new(5.0, 6.0))
+            .line_to(Vertex::new(6.0, 6.0))
+            .close()
+            .build()
+            .unwrap();
+
+        assert!(!path.subpath_starts.is_empty());
+        assert!(Polygon::new(path).is_err());
+    }
+
+    #[test]
+    fn test_square_area() {
+        let square = unit_square();
+        assert!((square.area() - 16.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_contains_inside_and_outside_points() {
+        let square = unit_square();
+
+        assert!(square.contains(&Vertex::new(2.0, 2.0)));
+        assert!(!square.contains(&Vertex::new(5.0, 5.0)));
+        assert!(!square.contains(&Vertex::new(-1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_shortest_path_goes_around_obstacle() {
+        let square = unit_square();
+
+        let start = Vertex::new(2.0, -2.0);
+        let goal = Vertex::new(2.0, 6.0);
+
+        let path = square
+            .shortest_path(start, goal, &Euclidean)
+            .expect("deveria existir um caminho contornando o quadrado");
+
+        assert!(!path.lines.is_empty());
+        assert_eq!(path.lines[0].starting_vertex, start);
+        assert_eq!(path.lines.last().unwrap().ending_vertex, goal);
+
+        // O caminho contornando o obstáculo deve ser mais longo que a distância direta, que
+        // passaria por dentro do quadrado.
+        let direct_distance = Euclidean.distance(&start, &goal);
+        assert!(path.calculate_full_distance(&Euclidean) > direct_distance);
+    }
+
+    #[test]
+    fn test_shortest_path_direct_when_unobstructed() {
+        let square = unit_square();
+
+        let start = Vertex::new(-2.0, -2.0);
+        let goal = Vertex::new(-2.0, 6.0);
+
+        let path = square
+            .shortest_path(start, goal, &Euclidean)
+            .expect("deveria existir um caminho direto, sem obstrução");
+
+        let direct_distance = Euclidean.distance(&start, &goal);
+        assert!((path.calculate_full_distance(&Euclidean) - direct_distance).abs() < 1e-9);
+    }
+}
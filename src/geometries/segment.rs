@@ -0,0 +1,89 @@
+use std::f64::consts::{PI, TAU};
+
+use super::{line::Line, vertex::Vertex};
+
+/// Um trecho de caminho: uma reta ou um arco circular.
+///
+/// Introduzido para representar rotas factíveis de veículos com raio de curvatura mínimo
+/// (aeronaves de asa fixa, carros), que não conseguem realizar curvas instantâneas como as
+/// junções entre [`Line`]s de um [`super::path::Path`] exigem.
+pub enum Segment {
+    Straight(Line),
+    Arc(Arc),
+}
+
+impl Segment {
+    /// Retorna o vértice onde o trecho começa.
+    pub fn starting_vertex(&self) -> Vertex {
+        match self {
+            Segment::Straight(line) => line.starting_vertex,
+            Segment::Arc(arc) => arc.starting_vertex(),
+        }
+    }
+
+    /// Retorna o vértice onde o trecho termina.
+    pub fn ending_vertex(&self) -> Vertex {
+        match self {
+            Segment::Straight(line) => line.ending_vertex,
+            Segment::Arc(arc) => arc.ending_vertex(),
+        }
+    }
+
+    /// Calcula o comprimento do trecho no plano local (reta: distância euclidiana; arco:
+    /// `raio * ângulo_varrido`).
+    pub fn length(&self) -> f64 {
+        match self {
+            Segment::Straight(line) => line.calculate_euclidean_distance(),
+            Segment::Arc(arc) => arc.length(),
+        }
+    }
+}
+
+/// Um arco circular, percorrido a partir de `start_angle` por `sweep_angle` radianos, no
+/// sentido horário (`clockwise = true`) ou anti-horário (`clockwise = false`).
+///
+/// Os ângulos são medidos como em um plano cartesiano padrão (latitude no eixo x, longitude no
+/// eixo y), a mesma aproximação planar local usada pela métrica `Euclidean` de
+/// [`super::path::Metric`] — não há correção de projeção geográfica.
+pub struct Arc {
+    pub center: Vertex,
+    pub radius: f64,
+    pub start_angle: f64,
+    pub sweep_angle: f64,
+    pub clockwise: bool,
+}
+
+impl Arc {
+    /// Ponto do arco no ângulo `angle` (radianos), medido a partir do centro.
+    fn point_at_angle(&self, angle: f64) -> Vertex {
+        Vertex::new(
+            self.center.latitude + self.radius * angle.cos(),
+            self.center.longitude + self.radius * angle.sin(),
+        )
+    }
+
+    pub fn starting_vertex(&self) -> Vertex {
+        self.point_at_angle(self.start_angle)
+    }
+
+    pub fn ending_vertex(&self) -> Vertex {
+        let end_angle = if self.clockwise {
+            self.start_angle - self.sweep_angle
+        } else {
+            self.start_angle + self.sweep_angle
+        };
+        self.point_at_angle(end_angle)
+    }
+
+    /// Comprimento de arco: `raio * ângulo_varrido_em_radianos`.
+    pub fn length(&self) -> f64 {
+        self.radius * self.sweep_angle
+    }
+}
+
+/// Normaliza um ângulo em radianos para o intervalo `[0, 2π)`.
+pub(super) fn normalize_angle(angle: f64) -> f64 {
+    angle.rem_euclid(TAU)
+}
+
+pub(super) const HALF_PI: f64 = PI / 2.0;
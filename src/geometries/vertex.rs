@@ -18,6 +18,34 @@ impl Vertex {
     pub fn is_equal(&self, other_vertex: &Vertex) -> bool {
         self == other_vertex
     }
+
+    /// Cria um novo vértice, validando que a latitude está em [-90, 90] e a longitude em
+    /// [-180, 180].
+    pub fn new_checked(latitude: f64, longitude: f64) -> Result<Self, String> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(format!(
+                "Latitude fora do intervalo permitido [-90, 90]: {}",
+                latitude
+            ));
+        }
+
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(format!(
+                "Longitude fora do intervalo permitido [-180, 180]: {}",
+                longitude
+            ));
+        }
+
+        Ok(Self::new(latitude, longitude))
+    }
+
+    /// Retorna uma cópia deste vértice com a longitude normalizada para o intervalo [-180, 180].
+    pub fn normalized(&self) -> Self {
+        let wrapped = (self.longitude + 180.0).rem_euclid(360.0) - 180.0;
+        let longitude = if wrapped == -180.0 { 180.0 } else { wrapped };
+
+        Self::new(self.latitude, longitude)
+    }
 }
 
 impl Display for Vertex {
@@ -64,6 +92,33 @@ mod tests {
         assert_ne!(vertex1, vertex3);
     }
 
+    #[test]
+    fn test_vertex_new_checked_accepts_valid_coordinates() {
+        let vertex = Vertex::new_checked(45.0, -90.0);
+        assert!(vertex.is_ok());
+    }
+
+    #[test]
+    fn test_vertex_new_checked_rejects_out_of_range_latitude() {
+        let vertex = Vertex::new_checked(91.0, 0.0);
+        assert!(vertex.is_err());
+    }
+
+    #[test]
+    fn test_vertex_new_checked_rejects_out_of_range_longitude() {
+        let vertex = Vertex::new_checked(0.0, 181.0);
+        assert!(vertex.is_err());
+    }
+
+    #[test]
+    fn test_vertex_normalized_wraps_longitude() {
+        let vertex = Vertex::new(10.0, 270.0);
+        let normalized = vertex.normalized();
+
+        assert_eq!(normalized.latitude, 10.0);
+        assert!((normalized.longitude - (-90.0)).abs() < 1e-9);
+    }
+
     #[test]
     fn test_vertex_clone() {
         let vertex1 = Vertex::new(10.0, -20.0);
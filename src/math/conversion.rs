@@ -11,6 +11,9 @@
 //!   amplamente utilizada em cálculos geodésicos.
 //! - **Conversão de WGS84 para Web Mercator**: Transforma coordenadas no sistema geográfico WGS84
 //!   (EPSG:4326) para o sistema de projeção Web Mercator (EPSG:3857), comumente usado em mapas web.
+//! - **Conversão de WGS84 para UTM**: Transforma coordenadas WGS84 para o sistema UTM (Universal
+//!   Transverse Mercator), usado em levantamentos topográficos e aplicações de SIG que padronizam
+//!   coordenadas métricas em grade, com conversão inversa correspondente.
 //!
 //! ## Exemplos
 //!
@@ -41,12 +44,22 @@
 //!
 //! ## Futuras Expansões
 //! Este módulo pode ser estendido para incluir:
-//! - Conversões entre outros sistemas de referência, como UTM (Universal Transverse Mercator).
 //! - Suporte para sistemas tridimensionais, incluindo altitude.
 //! - Conversão de coordenadas inversa (Web Mercator para WGS84).
 use crate::{consts::EARTH_RADIUS_METERS, geometries::vertex::Vertex};
 use std::f64::consts::PI;
 
+/// Semieixo maior do elipsoide WGS84, em metros.
+const UTM_SEMI_MAJOR_AXIS: f64 = 6378137.0;
+/// Achatamento do elipsoide WGS84.
+const UTM_FLATTENING: f64 = 1.0 / 298.257222101;
+/// Fator de escala no meridiano central de cada fuso UTM.
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+/// Falso leste aplicado a todas as coordenadas UTM, em metros.
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+/// Falso norte aplicado a coordenadas do hemisfério sul, em metros.
+const UTM_FALSE_NORTHING_SOUTH: f64 = 10_000_000.0;
+
 /// Converte graus para radianos.
 pub fn degrees_to_radians(degree: f64) -> f64 {
     degree * PI / 180.0
@@ -90,3 +103,212 @@ pub fn conversion_wgs84_web_mercator(vertex: &Vertex) -> Vertex {
 
     Vertex::new(y, x)
 }
+
+/// Determina o fuso UTM (1 a 60) que contém uma dada longitude.
+///
+/// # Fórmula
+/// ```text
+/// zone = floor((lon + 180) / 6) + 1
+/// ```
+pub fn utm_zone_from_longitude(longitude_deg: f64) -> u8 {
+    (((longitude_deg + 180.0) / 6.0).floor() as u8).min(59) + 1
+}
+
+/// Meridiano central, em graus, de um fuso UTM.
+fn utm_central_meridian(zone: u8) -> f64 {
+    (zone as f64 - 1.0) * 6.0 - 180.0 + 3.0
+}
+
+/// Coeficientes da série de Krüger (ordem 3) usados para projetar a latitude/longitude conforme
+/// na esfera auxiliar para o plano transverso de Mercator, e vice-versa.
+struct KrugerSeries {
+    a: f64,
+    alpha: [f64; 3],
+    beta: [f64; 3],
+}
+
+impl KrugerSeries {
+    fn for_wgs84() -> Self {
+        let n = UTM_FLATTENING / (2.0 - UTM_FLATTENING);
+
+        let a = UTM_SEMI_MAJOR_AXIS / (1.0 + n)
+            * (1.0 + n.powi(2) / 4.0 + n.powi(4) / 64.0);
+
+        let alpha = [
+            n / 2.0 - (2.0 / 3.0) * n.powi(2) + (5.0 / 16.0) * n.powi(3),
+            (13.0 / 48.0) * n.powi(2) - (3.0 / 5.0) * n.powi(3),
+            (61.0 / 240.0) * n.powi(3),
+        ];
+
+        let beta = [
+            n / 2.0 - (2.0 / 3.0) * n.powi(2) + (37.0 / 96.0) * n.powi(3),
+            (1.0 / 48.0) * n.powi(2) + (1.0 / 15.0) * n.powi(3),
+            (17.0 / 480.0) * n.powi(3),
+        ];
+
+        Self { a, alpha, beta }
+    }
+}
+
+/// Converte um ponto WGS84 (EPSG:4326) para coordenadas UTM (Universal Transverse Mercator).
+///
+/// # Parâmetros
+/// - `vertex`: Ponto no sistema de coordenadas WGS84, com latitude e longitude em graus.
+/// - `zone`: Fuso UTM (1 a 60) em que a projeção será calculada; use [`utm_zone_from_longitude`]
+///   para escolher o fuso a partir da longitude.
+///
+/// # Retorno
+/// Uma tupla `(easting, northing, convergence)`: o leste e o norte em metros e a convergência
+/// meridiana em graus. O hemisfério é inferido do sinal da latitude, que determina o falso norte.
+///
+/// # Notas
+/// - Usa o elipsoide WGS84 (a = 6378137.0, f = 1/298.257222101) com a série de Krüger para a
+///   projeção transversa de Mercator.
+pub fn conversion_wgs84_utm(vertex: &Vertex, zone: u8) -> (f64, f64, f64) {
+    let series = KrugerSeries::for_wgs84();
+    let n = UTM_FLATTENING / (2.0 - UTM_FLATTENING);
+    let e_prime = (2.0 * n.sqrt()) / (1.0 + n);
+
+    let phi = degrees_to_radians(vertex.latitude);
+    let lambda = degrees_to_radians(vertex.longitude - utm_central_meridian(zone));
+
+    let conformal_correction = e_prime * (e_prime * phi.sin()).atanh();
+    let t = (phi.sin().atanh() - conformal_correction).sinh();
+
+    let xi_prime = t.atan2(lambda.cos());
+    let eta_prime = (lambda.sin() / (1.0 + t * t).sqrt()).atanh();
+
+    let mut xi = xi_prime;
+    let mut eta = eta_prime;
+    let mut p = 1.0;
+    let mut q = 0.0;
+
+    for (j0, alpha_j) in series.alpha.iter().enumerate() {
+        let j = (j0 + 1) as f64;
+        xi += alpha_j * (2.0 * j * xi_prime).sin() * (2.0 * j * eta_prime).cosh();
+        eta += alpha_j * (2.0 * j * xi_prime).cos() * (2.0 * j * eta_prime).sinh();
+
+        p += 2.0 * j * alpha_j * (2.0 * j * xi_prime).cos() * (2.0 * j * eta_prime).cosh();
+        q += 2.0 * j * alpha_j * (2.0 * j * xi_prime).sin() * (2.0 * j * eta_prime).sinh();
+    }
+
+    let easting = UTM_SCALE_FACTOR * series.a * eta + UTM_FALSE_EASTING;
+
+    let false_northing = if vertex.latitude < 0.0 {
+        UTM_FALSE_NORTHING_SOUTH
+    } else {
+        0.0
+    };
+    let northing = UTM_SCALE_FACTOR * series.a * xi + false_northing;
+
+    let convergence_rad = (xi_prime.tan() * eta_prime.tanh()).atan() + (q / p).atan();
+    let convergence = convergence_rad.to_degrees();
+
+    (easting, northing, convergence)
+}
+
+/// Converte coordenadas UTM de volta para um ponto WGS84 (EPSG:4326).
+///
+/// # Parâmetros
+/// - `easting`: Leste UTM, em metros.
+/// - `northing`: Norte UTM, em metros.
+/// - `zone`: Fuso UTM (1 a 60) em que as coordenadas foram projetadas.
+/// - `southern_hemisphere`: `true` se as coordenadas pertencem ao hemisfério sul, usado para
+///   desfazer o falso norte aplicado na projeção direta.
+///
+/// # Retorno
+/// O `Vertex` correspondente em WGS84.
+pub fn conversion_utm_wgs84(
+    easting: f64,
+    northing: f64,
+    zone: u8,
+    southern_hemisphere: bool,
+) -> Vertex {
+    let series = KrugerSeries::for_wgs84();
+
+    let false_northing = if southern_hemisphere {
+        UTM_FALSE_NORTHING_SOUTH
+    } else {
+        0.0
+    };
+
+    let xi = (northing - false_northing) / (UTM_SCALE_FACTOR * series.a);
+    let eta = (easting - UTM_FALSE_EASTING) / (UTM_SCALE_FACTOR * series.a);
+
+    let mut xi_prime = xi;
+    let mut eta_prime = eta;
+
+    for (j0, beta_j) in series.beta.iter().enumerate() {
+        let j = (j0 + 1) as f64;
+        xi_prime -= beta_j * (2.0 * j * xi).sin() * (2.0 * j * eta).cosh();
+        eta_prime -= beta_j * (2.0 * j * xi).cos() * (2.0 * j * eta).sinh();
+    }
+
+    let conformal_lat = (xi_prime.sin() / eta_prime.cosh()).asin();
+    let n = UTM_FLATTENING / (2.0 - UTM_FLATTENING);
+    let beta_to_phi = [
+        2.0 * n - (2.0 / 3.0) * n.powi(2) - 2.0 * n.powi(3),
+        (7.0 / 3.0) * n.powi(2) - (8.0 / 5.0) * n.powi(3),
+        (56.0 / 15.0) * n.powi(3),
+    ];
+
+    let mut phi = conformal_lat;
+    for (j0, coeff) in beta_to_phi.iter().enumerate() {
+        let j = (j0 + 1) as f64;
+        phi += coeff * (2.0 * j * conformal_lat).sin();
+    }
+
+    let lambda = (eta_prime.sinh() / xi_prime.cos()).atan();
+
+    let latitude = phi.to_degrees();
+    let longitude = lambda.to_degrees() + utm_central_meridian(zone);
+
+    Vertex::new(latitude, longitude)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Converte `vertex` para UTM e de volta, verificando que o resultado fica a poucos metros
+    /// do ponto original — cobre a regressão em que `conformal_correction` aplicava um `atanh`
+    /// extra e introduzia dezenas de metros de erro de latitude fora do equador.
+    fn assert_round_trips(vertex: Vertex) {
+        let zone = utm_zone_from_longitude(vertex.longitude);
+        let (easting, northing, _convergence) = conversion_wgs84_utm(&vertex, zone);
+        let roundtrip = conversion_utm_wgs84(easting, northing, zone, vertex.latitude < 0.0);
+
+        assert!(
+            (roundtrip.latitude - vertex.latitude).abs() < 1e-6,
+            "latitude ida-e-volta divergiu: esperado {}, obtido {}",
+            vertex.latitude,
+            roundtrip.latitude
+        );
+        assert!(
+            (roundtrip.longitude - vertex.longitude).abs() < 1e-6,
+            "longitude ida-e-volta divergiu: esperado {}, obtido {}",
+            vertex.longitude,
+            roundtrip.longitude
+        );
+    }
+
+    #[test]
+    fn test_utm_round_trip_at_equator() {
+        assert_round_trips(Vertex::new(0.0, 9.0));
+    }
+
+    #[test]
+    fn test_utm_round_trip_at_45_north() {
+        assert_round_trips(Vertex::new(45.0, 9.0));
+    }
+
+    #[test]
+    fn test_utm_round_trip_at_60_north() {
+        assert_round_trips(Vertex::new(60.0, 9.0));
+    }
+
+    #[test]
+    fn test_utm_round_trip_in_southern_hemisphere() {
+        assert_round_trips(Vertex::new(-33.0, -70.0));
+    }
+}
@@ -3,32 +3,32 @@
 //! As funções implementadas incluem:
 //! - **Fórmula de Haversine**: Calcula distâncias aproximadas entre dois pontos na superfície da Terra,
 //!   assumindo-a como uma esfera perfeita. É útil para estimativas rápidas, mas não é altamente precisa.
-//! - **Fórmula de Vincenty**: Calcula distâncias geodésicas precisas entre dois pontos na superfície de um elipsoide,
-//!   considerando o achatamento da Terra. Ideal para cálculos mais precisos.
+//! - **Algoritmo geodésico (Karney)**: Calcula distâncias geodésicas precisas entre dois pontos na
+//!   superfície de um elipsoide, considerando o achatamento da Terra. Converge para qualquer par
+//!   de pontos, incluindo os antipodais e quase antipodais, através de um método de Newton
+//!   protegido por bisseção.
 //!
 //! # Notas
 //! - Use a fórmula de Haversine para cálculos rápidos e simples.
-//! - Prefira a fórmula de Vincenty quando a precisão for fundamental, especialmente para grandes distâncias ou aplicações científicas.
+//! - Prefira o algoritmo geodésico quando a precisão for fundamental, especialmente para grandes distâncias ou aplicações científicas.
 //! - Este módulo depende de constantes definidas no módulo `consts`, como o raio médio da Terra (`EARTH_RADIUS_KM`) e parâmetros do elipsoide (semi-eixos maior e menor, e achatamento).
 //!
 //! # Exemplos
 //!
 //! ```rust
 //! use RustSpatial::geometries::vertex::Vertex;
-//! use RustSpatial::math::distance::{calculate_earth_radius_distance_haversine, calculate_earth_radius_distance_vincenty};
+//! use RustSpatial::math::distance::{calculate_earth_radius_distance_haversine, calculate_earth_radius_distance_geodesic};
 //!
 //! let vertex1 = Vertex::new(10.0, 20.0);
 //! let vertex2 = Vertex::new(15.0, 25.0);
 //!
 //! // Cálculo usando a fórmula de Haversine
 //! let haversine_distance = calculate_earth_radius_distance_haversine(&vertex1, &vertex2);
-//! println!("Distância aproximada (Haversine): {:.2} km", haversine_distance);
+//! println!("Distância aproximada (Haversine): {:.2} km", haversine_distance.kilometers());
 //!
-//! // Cálculo usando a fórmula de Vincenty
-//! match calculate_earth_radius_distance_vincenty(&vertex1, &vertex2) {
-//!     Ok(vincenty_distance) => println!("Distância precisa (Vincenty): {:.2} m", vincenty_distance),
-//!     Err(err) => println!("Erro no cálculo de Vincenty: {}", err),
-//! }
+//! // Cálculo preciso usando o algoritmo geodésico
+//! let geodesic_distance = calculate_earth_radius_distance_geodesic(&vertex1, &vertex2);
+//! println!("Distância precisa (geodésica): {:.2} m", geodesic_distance);
 //! ```
 use crate::{
     consts::{EARTH_RADIUS_KM, FLATTENING, SEMI_MAJOR_AXIS_LENGTH, SEMI_MINOR_AXIS_LENGTH},
@@ -37,6 +37,59 @@ use crate::{
 
 use super::conversion::degrees_to_radians;
 
+const METERS_PER_KILOMETER: f64 = 1000.0;
+const METERS_PER_MILE: f64 = 1609.344;
+const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+const METERS_PER_FOOT: f64 = 0.3048;
+
+/// Representa uma distância geográfica, armazenada internamente em metros, com conversão
+/// transparente para as unidades mais comuns.
+///
+/// Consolidar o resultado dos cálculos de distância em um único tipo evita a ambiguidade de
+/// ter funções que retornam quilômetros (Haversine) e outras que retornam metros (Vincenty),
+/// deixando a escolha da unidade a cargo de quem consome o valor.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Distance {
+    meters: f64,
+}
+
+impl Distance {
+    /// Cria uma `Distance` a partir de um valor em metros.
+    pub fn from_meters(meters: f64) -> Self {
+        Self { meters }
+    }
+
+    /// Cria uma `Distance` a partir de um valor em quilômetros.
+    pub fn from_kilometers(kilometers: f64) -> Self {
+        Self::from_meters(kilometers * METERS_PER_KILOMETER)
+    }
+
+    /// Retorna a distância em metros.
+    pub fn meters(&self) -> f64 {
+        self.meters
+    }
+
+    /// Retorna a distância em quilômetros.
+    pub fn kilometers(&self) -> f64 {
+        self.meters / METERS_PER_KILOMETER
+    }
+
+    /// Retorna a distância em milhas terrestres.
+    pub fn miles(&self) -> f64 {
+        self.meters / METERS_PER_MILE
+    }
+
+    /// Retorna a distância em milhas náuticas.
+    pub fn nautical_miles(&self) -> f64 {
+        self.meters / METERS_PER_NAUTICAL_MILE
+    }
+
+    /// Retorna a distância em pés.
+    pub fn feet(&self) -> f64 {
+        self.meters / METERS_PER_FOOT
+    }
+}
+
 /// Calcula a distância entre dois vértices na superfície da Terra utilizando a fórmula de Haversine.
 ///
 /// A fórmula de Haversine é usada para calcular a distância ao longo da superfície de uma esfera,
@@ -47,7 +100,8 @@ use super::conversion::degrees_to_radians;
 /// - `ending_vertex`: Referência para o vértice final, contendo latitude e longitude em graus.
 ///
 /// # Retorno
-/// Retorna a distância entre os dois vértices em quilômetros.
+/// Retorna a distância entre os dois vértices como uma [`Distance`], permitindo ao chamador
+/// escolher a unidade desejada.
 ///
 /// # Fórmula
 /// A fórmula de Haversine calcula a distância como:
@@ -64,13 +118,13 @@ use super::conversion::degrees_to_radians;
 /// # Exemplo
 /// ```rust
 /// use RustSpatial::geometries::vertex::Vertex;
-/// use RustSpatial::math::distance::calculate_earth_radius_distance;
+/// use RustSpatial::math::distance::calculate_earth_radius_distance_haversine;
 ///
 /// let vertex1 = Vertex { latitude: 0.0, longitude: 0.0 };
 /// let vertex2 = Vertex { latitude: 0.0, longitude: 1.0 };
 ///
-/// let distance = calculate_earth_radius_distance(&vertex1, &vertex2);
-/// println!("Distância: {:.2} km", distance); // Saída: ~111.19 km
+/// let distance = calculate_earth_radius_distance_haversine(&vertex1, &vertex2);
+/// println!("Distância: {:.2} km", distance.kilometers()); // Saída: ~111.19 km
 /// ```
 ///
 /// # Notas
@@ -79,7 +133,7 @@ use super::conversion::degrees_to_radians;
 pub fn calculate_earth_radius_distance_haversine(
     starting_vertex: &Vertex,
     ending_vertex: &Vertex,
-) -> f64 {
+) -> Distance {
     let lat1_rad = degrees_to_radians(starting_vertex.latitude);
     let lat2_rad = degrees_to_radians(ending_vertex.latitude);
     let lon1_rad = degrees_to_radians(starting_vertex.longitude);
@@ -92,108 +146,616 @@ pub fn calculate_earth_radius_distance_haversine(
         + lat1_rad.cos() * lat2_rad.cos() * (dist_lon / 2.0).sin().powi(2);
     let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
 
-    EARTH_RADIUS_KM * c
+    Distance::from_kilometers(EARTH_RADIUS_KM * c)
+}
+
+/// Calcula o rumo inicial (azimute direto) de um vértice para outro, seguindo a rota de grande círculo.
+///
+/// # Parâmetros
+/// - `starting_vertex`: Referência para o vértice de partida, contendo latitude e longitude em graus.
+/// - `ending_vertex`: Referência para o vértice de chegada, contendo latitude e longitude em graus.
+///
+/// # Retorno
+/// O rumo inicial em graus, medido no sentido horário a partir do norte, normalizado para [0, 360).
+///
+/// # Fórmula
+/// ```text
+/// θ = atan2(sin(Δlon) · cos(lat2), cos(lat1) · sin(lat2) − sin(lat1) · cos(lat2) · cos(Δlon))
+/// ```
+///
+/// # Exemplo
+/// ```rust
+/// use RustSpatial::geometries::vertex::Vertex;
+/// use RustSpatial::math::distance::initial_bearing;
+///
+/// let vertex1 = Vertex::new(0.0, 0.0);
+/// let vertex2 = Vertex::new(0.0, 1.0);
+/// let bearing = initial_bearing(&vertex1, &vertex2);
+/// println!("Rumo inicial: {:.2}°", bearing); // Aproximadamente 90°
+/// ```
+pub fn initial_bearing(starting_vertex: &Vertex, ending_vertex: &Vertex) -> f64 {
+    let lat1_rad = degrees_to_radians(starting_vertex.latitude);
+    let lat2_rad = degrees_to_radians(ending_vertex.latitude);
+    let delta_lon = degrees_to_radians(ending_vertex.longitude - starting_vertex.longitude);
+
+    let y = delta_lon.sin() * lat2_rad.cos();
+    let x = lat1_rad.cos() * lat2_rad.sin() - lat1_rad.sin() * lat2_rad.cos() * delta_lon.cos();
+
+    normalize_bearing(y.atan2(x).to_degrees())
+}
+
+/// Calcula o rumo final (azimute de chegada) da rota de grande círculo entre dois vértices.
+///
+/// Corresponde ao rumo inicial da rota inversa (de `ending_vertex` para `starting_vertex`),
+/// somado a 180° para expressar a direção de chegada em vez da direção de partida da rota inversa.
+///
+/// # Retorno
+/// O rumo final em graus, normalizado para [0, 360).
+pub fn final_bearing(starting_vertex: &Vertex, ending_vertex: &Vertex) -> f64 {
+    let reverse_bearing = initial_bearing(ending_vertex, starting_vertex);
+    normalize_bearing(reverse_bearing + 180.0)
+}
+
+/// Normaliza um rumo em graus para o intervalo [0, 360).
+fn normalize_bearing(bearing_deg: f64) -> f64 {
+    bearing_deg.rem_euclid(360.0)
+}
+
+/// Calcula a distância meridional: o comprimento do arco de meridiano do equador até uma dada
+/// latitude, sobre a superfície do elipsoide.
+///
+/// É a grandeza `M` usada como base das projeções transversas de Mercator (como a UTM) e de
+/// outros cálculos de grade.
+///
+/// # Parâmetros
+/// - `latitude_deg`: Latitude em graus.
+///
+/// # Retorno
+/// A distância meridional em metros.
+///
+/// # Fórmula
+/// Usa uma série truncada no terceiro achatamento `n = f/(2−f)`:
+/// ```text
+/// A = a·(1 + n²/4 + n⁴/64) / (1 + n)
+/// M = A·(φ − (3n/2 − 9n³/16)·sin(2φ) + (15n²/16)·sin(4φ) − (35n³/48)·sin(6φ))
+/// ```
+pub fn meridional_distance(latitude_deg: f64) -> f64 {
+    let phi = degrees_to_radians(latitude_deg);
+    let n = FLATTENING / (2.0 - FLATTENING);
+
+    let a = SEMI_MAJOR_AXIS_LENGTH * (1.0 + n * n / 4.0 + n.powi(4) / 64.0) / (1.0 + n);
+
+    let term1 = 3.0 / 2.0 * n - 9.0 / 16.0 * n.powi(3);
+    let term2 = 15.0 / 16.0 * n.powi(2);
+    let term3 = 35.0 / 48.0 * n.powi(3);
+
+    a * (phi - term1 * (2.0 * phi).sin() + term2 * (4.0 * phi).sin() - term3 * (6.0 * phi).sin())
+}
+
+/// Calcula o vértice de destino a partir de um ponto inicial, um azimute (rumo) e uma distância percorrida.
+///
+/// Esta é a operação inversa do cálculo de distância: dado um ponto de partida, uma direção inicial
+/// e quão longe se deseja ir, retorna o ponto de chegada sobre a esfera terrestre.
+///
+/// # Parâmetros
+/// - `start`: Referência para o vértice de partida, contendo latitude e longitude em graus.
+/// - `bearing_deg`: Rumo inicial em graus, medido no sentido horário a partir do norte (0° a 360°).
+/// - `distance_km`: Distância a percorrer, em quilômetros.
+///
+/// # Retorno
+/// O `Vertex` de destino, com a longitude normalizada para o intervalo [-180, 180].
+///
+/// # Fórmula
+/// ```text
+/// δ = distance_km / R
+/// lat2 = asin(sin(lat1) · cos(δ) + cos(lat1) · sin(δ) · cos(θ))
+/// lon2 = lon1 + atan2(sin(θ) · sin(δ) · cos(lat1), cos(δ) − sin(lat1) · sin(lat2))
+/// ```
+/// Onde `θ` é o rumo em radianos, `δ` é a distância angular percorrida e `R` é o raio da Terra
+/// (fornecido pela constante `EARTH_RADIUS_KM`).
+///
+/// # Exemplo
+/// ```rust
+/// use RustSpatial::geometries::vertex::Vertex;
+/// use RustSpatial::math::distance::destination;
+///
+/// let start = Vertex::new(0.0, 0.0);
+/// let end = destination(&start, 90.0, 111.19);
+/// println!("Destino: {}", end); // Aproximadamente Vertex(lat: 0.0, lon: 1.0)
+/// ```
+pub fn destination(start: &Vertex, bearing_deg: f64, distance_km: f64) -> Vertex {
+    let lat1_rad = degrees_to_radians(start.latitude);
+    let lon1_rad = degrees_to_radians(start.longitude);
+    let theta = degrees_to_radians(bearing_deg);
+    let delta = distance_km / EARTH_RADIUS_KM;
+
+    let lat2_rad = (lat1_rad.sin() * delta.cos() + lat1_rad.cos() * delta.sin() * theta.cos()).asin();
+    let lon2_rad = lon1_rad
+        + (theta.sin() * delta.sin() * lat1_rad.cos())
+            .atan2(delta.cos() - lat1_rad.sin() * lat2_rad.sin());
+
+    let lat2_deg = lat2_rad.to_degrees();
+    let lon2_deg = normalize_longitude(lon2_rad.to_degrees());
+
+    Vertex::new(lat2_deg, lon2_deg)
+}
+
+/// Normaliza uma longitude em graus para o intervalo [-180, 180].
+fn normalize_longitude(longitude_deg: f64) -> f64 {
+    let wrapped = (longitude_deg + 180.0).rem_euclid(360.0) - 180.0;
+
+    // rem_euclid nunca é negativo, mas pode retornar -180.0 quando o resultado cai exatamente
+    // na borda; mantemos a convenção de que o intervalo é aberto em -180 e fechado em 180.
+    if wrapped == -180.0 {
+        180.0
+    } else {
+        wrapped
+    }
+}
+
+/// Par (valor, derivada) usado para obter `dλ_atualizado/dλ` exatamente por diferenciação
+/// automática direta ("forward-mode"), em vez de aproximá-la por diferença finita: cada
+/// operação aritmética propaga sua própria derivada mecanicamente pela regra da cadeia, o que
+/// elimina tanto o erro de truncamento da diferença finita quanto o risco de transcrever errado
+/// a extensa fórmula fechada de `dλ/dσ` à mão.
+#[derive(Clone, Copy)]
+struct Dual {
+    value: f64,
+    derivative: f64,
+}
+
+impl Dual {
+    fn constant(value: f64) -> Self {
+        Self {
+            value,
+            derivative: 0.0,
+        }
+    }
+
+    fn variable(value: f64) -> Self {
+        Self {
+            value,
+            derivative: 1.0,
+        }
+    }
+}
+
+impl std::ops::Add for Dual {
+    type Output = Dual;
+
+    fn add(self, rhs: Dual) -> Dual {
+        Dual {
+            value: self.value + rhs.value,
+            derivative: self.derivative + rhs.derivative,
+        }
+    }
+}
+
+impl std::ops::Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, rhs: Dual) -> Dual {
+        Dual {
+            value: self.value - rhs.value,
+            derivative: self.derivative - rhs.derivative,
+        }
+    }
+}
+
+impl std::ops::Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual {
+            value: self.value * rhs.value,
+            derivative: self.derivative * rhs.value + self.value * rhs.derivative,
+        }
+    }
+}
+
+impl std::ops::Div for Dual {
+    type Output = Dual;
+
+    fn div(self, rhs: Dual) -> Dual {
+        Dual {
+            value: self.value / rhs.value,
+            derivative: (self.derivative * rhs.value - self.value * rhs.derivative)
+                / (rhs.value * rhs.value),
+        }
+    }
+}
+
+fn dual_sin(x: Dual) -> Dual {
+    Dual {
+        value: x.value.sin(),
+        derivative: x.value.cos() * x.derivative,
+    }
+}
+
+fn dual_cos(x: Dual) -> Dual {
+    Dual {
+        value: x.value.cos(),
+        derivative: -x.value.sin() * x.derivative,
+    }
+}
+
+fn dual_sqrt(x: Dual) -> Dual {
+    let value = x.value.sqrt();
+    Dual {
+        value,
+        derivative: x.derivative / (2.0 * value),
+    }
+}
+
+fn dual_atan2(y: Dual, x: Dual) -> Dual {
+    let value = y.value.atan2(x.value);
+    let denom = x.value * x.value + y.value * y.value;
+    Dual {
+        value,
+        derivative: (x.value * y.derivative - y.value * x.derivative) / denom,
+    }
+}
+
+/// Avalia `g(λ) = λ_atualizado(λ) − λ`, a função cuja raiz é a diferença de longitude auxiliar
+/// que resolve o triângulo esférico, junto com `dg/dλ` (exata, via [`Dual`]) e o comprimento de
+/// arco `σ` associado, necessário para a integral de distância final.
+fn evaluate_lambda(
+    lambda: f64,
+    lon_diff: f64,
+    sin_beta1: f64,
+    cos_beta1: f64,
+    sin_beta2: f64,
+    cos_beta2: f64,
+) -> (f64, f64, f64) {
+    let lambda_d = Dual::variable(lambda);
+    let sin_lambda = dual_sin(lambda_d);
+    let cos_lambda = dual_cos(lambda_d);
+
+    let cos_beta1_d = Dual::constant(cos_beta1);
+    let sin_beta1_d = Dual::constant(sin_beta1);
+    let cos_beta2_d = Dual::constant(cos_beta2);
+    let sin_beta2_d = Dual::constant(sin_beta2);
+
+    let term_a = cos_beta2_d * sin_lambda;
+    let term_b = cos_beta1_d * sin_beta2_d - sin_beta1_d * cos_beta2_d * cos_lambda;
+    let sin_sigma = dual_sqrt(term_a * term_a + term_b * term_b);
+    let cos_sigma = sin_beta1_d * sin_beta2_d + cos_beta1_d * cos_beta2_d * cos_lambda;
+
+    if sin_sigma.value == 0.0 {
+        // σ = 0 (β1 = β2 e λ = 0, o que inclui pontos coincidentes): o limite de λ_atualizado
+        // quando λ → 0 é `lon_diff`. σ = π numa simetria que degenera a esfera auxiliar:
+        // λ_atualizado = 0 é o único valor bem definido aqui.
+        let sigma = sin_sigma.value.atan2(cos_sigma.value);
+        let lambda_updated = if cos_sigma.value > 0.0 { lon_diff } else { 0.0 };
+        return (lambda_updated - lambda, -1.0, sigma);
+    }
+
+    let sigma = dual_atan2(sin_sigma, cos_sigma);
+
+    let sin_alpha = (cos_beta1_d * cos_beta2_d * sin_lambda) / sin_sigma;
+    let one = Dual::constant(1.0);
+    let two = Dual::constant(2.0);
+    let three = Dual::constant(3.0);
+    let four = Dual::constant(4.0);
+    let neg_one = Dual::constant(-1.0);
+    let flattening = Dual::constant(FLATTENING);
+    let sixteenth = Dual::constant(1.0 / 16.0);
+
+    let cos2_sigma_m = one - sin_alpha * sin_alpha;
+    let c = flattening
+        * sixteenth
+        * cos2_sigma_m
+        * (four + flattening * (four - three * cos2_sigma_m));
+
+    let lambda_updated = Dual::constant(lon_diff)
+        + (one - c)
+            * flattening
+            * sin_alpha
+            * (sigma
+                + c * sin_sigma * (cos2_sigma_m + c * cos_sigma * (neg_one + two * cos2_sigma_m)));
+
+    (
+        lambda_updated.value - lambda,
+        lambda_updated.derivative - 1.0,
+        sigma.value,
+    )
 }
 
-/// Calcula a distância geodésica entre dois pontos na superfície do elipsoide usando a fórmula de Vincenty.
+/// Resolve `g(λ) = 0` pelo método `rtsafe` (Newton protegido por bisseção, como em Numerical
+/// Recipes): dá um passo de Newton quando ele permanece dentro do intervalo de busca corrente e
+/// reduz o resíduo o suficiente, e cai para bisseção caso contrário.
+///
+/// `g(−π)` e `g(π)` sempre têm sinais opostos: na esfera auxiliar, `λ_atualizado(π)` e
+/// `λ_atualizado(−π)` colapsam exatamente em `lon_diff` (ou em `0`, no caso degenerado tratado em
+/// [`evaluate_lambda`]), de modo que `g(π) = lon_diff − π ≤ 0` e `g(−π) = lon_diff + π ≥ 0` para
+/// qualquer `lon_diff` em `[−π, π]`. O intervalo `[−π, π]` portanto sempre encerra uma raiz, o
+/// que garante a convergência do `rtsafe` para qualquer par de pontos, incluindo os antipodais e
+/// quase antipodais onde a derivada numérica por diferença finita falhava.
+fn solve_lambda(
+    lon_diff: f64,
+    sin_beta1: f64,
+    cos_beta1: f64,
+    sin_beta2: f64,
+    cos_beta2: f64,
+) -> (f64, f64) {
+    let eval =
+        |lambda: f64| evaluate_lambda(lambda, lon_diff, sin_beta1, cos_beta1, sin_beta2, cos_beta2);
+
+    let pi = std::f64::consts::PI;
+    let (f_at_neg_pi, _, _) = eval(-pi);
+    let (f_at_pos_pi, _, _) = eval(pi);
+
+    // Orienta o intervalo de forma que `f(lo) < 0 < f(hi)`.
+    let (mut lo, mut hi) = if f_at_neg_pi < f_at_pos_pi {
+        (-pi, pi)
+    } else {
+        (pi, -pi)
+    };
+
+    let mut lambda = 0.5 * (lo + hi);
+    let (mut f_val, mut f_der, mut sigma) = eval(lambda);
+    let mut dx_old = (hi - lo).abs();
+    let mut dx = dx_old;
+
+    for _ in 0..100 {
+        let newton_out_of_bounds =
+            ((lambda - hi) * f_der - f_val) * ((lambda - lo) * f_der - f_val) > 0.0;
+        let newton_too_slow = (2.0 * f_val).abs() > (dx_old * f_der).abs();
+
+        if newton_out_of_bounds || newton_too_slow {
+            dx_old = dx;
+            dx = 0.5 * (hi - lo);
+            lambda = lo + dx;
+        } else {
+            dx_old = dx;
+            dx = f_val / f_der;
+            lambda -= dx;
+        }
+
+        if dx.abs() < 1e-14 {
+            break;
+        }
+
+        let (new_val, new_der, new_sigma) = eval(lambda);
+        f_val = new_val;
+        f_der = new_der;
+        sigma = new_sigma;
+
+        if f_val < 0.0 {
+            lo = lambda;
+        } else {
+            hi = lambda;
+        }
+    }
+
+    (lambda, sigma)
+}
+
+/// Calcula a distância geodésica entre dois pontos na superfície do elipsoide, convergindo para
+/// qualquer par de pontos, incluindo os antipodais e quase antipodais onde a iteração de ponto
+/// fixo de Vincenty oscila sem convergir.
 ///
 /// # Parâmetros
 /// - `starting_vertex`: Referência para o vértice inicial, contendo latitude e longitude em graus.
 /// - `ending_vertex`: Referência para o vértice final, contendo latitude e longitude em graus.
 ///
 /// # Retorno
-/// A distância entre os dois pontos em metros.
-fn calculate_earth_radius_distance_vincenty(
+/// A distância entre os dois pontos, em metros.
+///
+/// # Algoritmo
+/// Segue a abordagem de Karney para o problema geodésico inverso: reduz as latitudes à
+/// latitude paramétrica (reduzida) `β = atan((1−f)·tan(φ))` e resolve o problema na esfera
+/// auxiliar. Em vez da iteração de ponto fixo de Vincenty — que pode divergir perto de pontos
+/// antipodais, pois a derivada da atualização de `λ` ultrapassa 1 em módulo nesse regime — a
+/// diferença de longitude auxiliar é encontrada com [`solve_lambda`], que combina o método de
+/// Newton (com a derivada exata de [`evaluate_lambda`]) e bisseção protegida, garantida a
+/// convergir porque `[−π, π]` sempre encerra a raiz. O comprimento do arco `σ₁₂` resultante é
+/// então convertido em distância através da série de integração em função do terceiro
+/// achatamento `n = f/(2−f)` (coeficientes `A₁`/`C₁`), que Karney usa em lugar dos termos em `u²`
+/// de Vincenty.
+pub fn calculate_earth_radius_distance_geodesic(
     starting_vertex: &Vertex,
     ending_vertex: &Vertex,
-) -> Result<f64, &'static str> {
-    // Converte coordenadas para radianos
+) -> f64 {
     let lat1_rad = degrees_to_radians(starting_vertex.latitude);
     let lat2_rad = degrees_to_radians(ending_vertex.latitude);
-    let lon1_rad = degrees_to_radians(starting_vertex.longitude);
-    let lon2_rad = degrees_to_radians(ending_vertex.longitude);
+    let lon_diff = degrees_to_radians(ending_vertex.longitude - starting_vertex.longitude);
 
-    // Diferença inicial de longitude
-    let mut lambda = lon2_rad - lon1_rad;
-    let mut lambda_prev;
-    let mut iter_limit = 100;
+    // Latitude reduzida (paramétrica) na esfera auxiliar.
+    let beta1 = ((1.0 - FLATTENING) * lat1_rad.tan()).atan();
+    let beta2 = ((1.0 - FLATTENING) * lat2_rad.tan()).atan();
 
-    let u1 = ((1.0 - FLATTENING) * lat1_rad.tan()).atan();
-    let u2 = ((1.0 - FLATTENING) * lat2_rad.tan()).atan();
+    let (_, sigma12) = solve_lambda(lon_diff, beta1.sin(), beta1.cos(), beta2.sin(), beta2.cos());
 
-    let sin_u1 = u1.sin();
-    let cos_u1 = u1.cos();
-    let sin_u2 = u2.sin();
-    let cos_u2 = u2.cos();
+    // Terceiro achatamento e série de integração de Karney para o comprimento do arco.
+    let n = FLATTENING / (2.0 - FLATTENING);
 
-    let mut cos2_sigma_m;
-    let mut sin_sigma;
-    let mut cos_sigma;
-    let mut sigma;
+    let a1 = (1.0 + n * n / 4.0 + n.powi(4) / 64.0) / (1.0 - n);
+    let c1_1 = -n / 2.0 + 3.0 / 16.0 * n.powi(3);
+    let c1_2 = -n.powi(2) / 16.0;
+    let c1_3 = -n.powi(3) / 48.0;
 
-    let mut sin_lambda;
-    let mut cos_lambda;
+    let i1 = a1
+        * (sigma12
+            + c1_1 * (2.0 * sigma12).sin()
+            + c1_2 * (4.0 * sigma12).sin()
+            + c1_3 * (6.0 * sigma12).sin());
 
-    loop {
-        if iter_limit == 0 {
-            return Err("Convergência não atingida");
-        }
+    SEMI_MINOR_AXIS_LENGTH * i1
+}
 
-        sin_lambda = lambda.sin();
-        cos_lambda = lambda.cos();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        sin_sigma = ((cos_u2 * sin_lambda).powi(2)
-            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
-        .sqrt();
+    #[test]
+    fn test_geodesic_distance_antipodal_points() {
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.0, 180.0);
 
-        if sin_sigma == 0.0 {
-            return Ok(0.0); // Pontos coincidentes
-        }
+        // Entre pontos antipodais, a geodésica mais curta passa pelos polos: meia volta pelo
+        // meridiano, 2 · M(90°), menor que a meia volta pelo equador (π · a).
+        let distance = calculate_earth_radius_distance_geodesic(&vertex1, &vertex2);
+        assert!((distance - 2.0 * meridional_distance(90.0)).abs() < 1000.0);
+    }
 
-        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
-        sigma = sin_sigma.atan2(cos_sigma);
+    #[test]
+    fn test_geodesic_distance_nearly_antipodal_points() {
+        // Caso clássico que faz a iteração de ponto fixo de Vincenty oscilar sem convergir.
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.5, 179.5);
 
-        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
-        cos2_sigma_m = 1.0 - sin_alpha.powi(2);
+        let distance = calculate_earth_radius_distance_geodesic(&vertex1, &vertex2);
+        assert!(distance.is_finite());
+        assert!(distance > 0.0);
+    }
 
-        let c = FLATTENING / 16.0 * cos2_sigma_m * (4.0 + FLATTENING * (4.0 - 3.0 * cos2_sigma_m));
+    #[test]
+    fn test_geodesic_distance_coincident_points() {
+        let vertex = Vertex::new(10.0, 20.0);
 
-        lambda_prev = lambda;
-        lambda = (lon2_rad - lon1_rad)
-            + (1.0 - c)
-                * FLATTENING
-                * sin_alpha
-                * (sigma
-                    + c * sin_sigma * (cos2_sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos2_sigma_m)));
+        assert_eq!(
+            calculate_earth_radius_distance_geodesic(&vertex, &vertex),
+            0.0
+        );
+    }
 
-        if (lambda - lambda_prev).abs() < 1e-12 {
-            break;
+    #[test]
+    fn test_geodesic_distance_matches_haversine_roughly() {
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.0, 1.0);
+
+        let geodesic_distance = calculate_earth_radius_distance_geodesic(&vertex1, &vertex2);
+        let haversine_distance =
+            calculate_earth_radius_distance_haversine(&vertex1, &vertex2).meters();
+
+        assert!((geodesic_distance - haversine_distance).abs() < 1000.0);
+    }
+
+    #[test]
+    fn test_geodesic_distance_symmetric_nearly_antipodal_pairs_converge() {
+        // Pares simétricos (lat2 = −lat1) perto da antípoda são o regime em que a iteração de
+        // ponto fixo de Vincenty mais sofre; varre uma faixa de latitudes para garantir que o
+        // solver `rtsafe` converge para todos eles, sem travar numa singularidade pontual.
+        let mut lat1_deg: f64 = 1.0;
+        while lat1_deg < 89.0 {
+            let mut lon_diff_deg: f64 = 179.0;
+            while lon_diff_deg < 180.0 {
+                let vertex1 = Vertex::new(lat1_deg, 0.0);
+                let vertex2 = Vertex::new(-lat1_deg, lon_diff_deg);
+
+                let distance = calculate_earth_radius_distance_geodesic(&vertex1, &vertex2);
+
+                assert!(
+                    distance.is_finite() && distance > 0.0,
+                    "não convergiu para lat1={} lon_diff={}: {}",
+                    lat1_deg,
+                    lon_diff_deg,
+                    distance
+                );
+
+                lon_diff_deg += 10.0;
+            }
+            lat1_deg += 10.0;
         }
+    }
+
+    #[test]
+    fn test_geodesic_distance_exactly_antipodal_symmetric_pair() {
+        // Caso degenerado em que a esfera auxiliar colapsa exatamente (σ = π): β1 = −β2 e
+        // λ = π, tratado em `evaluate_lambda` em vez de deixar o solver dividir por zero.
+        let vertex1 = Vertex::new(30.0, 0.0);
+        let vertex2 = Vertex::new(-30.0, 180.0);
 
-        iter_limit -= 1;
+        let distance = calculate_earth_radius_distance_geodesic(&vertex1, &vertex2);
+        assert!(distance.is_finite());
+        // Como todo par antipodal, a geodésica é a meia volta pelo meridiano, 2 · M(90°).
+        assert!((distance - 2.0 * meridional_distance(90.0)).abs() < 10_000.0);
     }
 
-    let u_squared = cos2_sigma_m
-        * (SEMI_MAJOR_AXIS_LENGTH * SEMI_MAJOR_AXIS_LENGTH
-            - SEMI_MINOR_AXIS_LENGTH * SEMI_MINOR_AXIS_LENGTH)
-        / (SEMI_MINOR_AXIS_LENGTH * SEMI_MINOR_AXIS_LENGTH);
-    let a_term = 1.0
-        + u_squared / 16384.0
-            * (4096.0 + u_squared * (-768.0 + u_squared * (320.0 - 175.0 * u_squared)));
-    let b_term =
-        u_squared / 1024.0 * (256.0 + u_squared * (-128.0 + u_squared * (74.0 - 47.0 * u_squared)));
+    #[test]
+    fn test_destination_reaches_expected_vertex() {
+        let start = Vertex::new(0.0, 0.0);
+        let end = destination(&start, 90.0, EARTH_RADIUS_KM * std::f64::consts::PI / 180.0);
 
-    let delta_sigma = b_term
-        * sin_sigma
-        * (cos2_sigma_m
-            + b_term / 4.0
-                * (cos_sigma * (-1.0 + 2.0 * cos2_sigma_m.powi(2))
-                    - b_term / 6.0
-                        * cos2_sigma_m
-                        * (-3.0 + 4.0 * sin_sigma.powi(2))
-                        * (-3.0 + 4.0 * cos2_sigma_m.powi(2))));
+        assert!((end.latitude - 0.0).abs() < 1e-6);
+        assert!((end.longitude - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_destination_round_trip_with_initial_bearing() {
+        let start = Vertex::new(-10.0, 25.0);
+        let bearing = 40.0;
+        let distance_km = 500.0;
+
+        let end = destination(&start, bearing, distance_km);
+        let recovered_bearing = initial_bearing(&start, &end);
+
+        assert!((recovered_bearing - bearing).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_final_bearing_along_equator_matches_initial_bearing() {
+        // Sobre o equador o rumo não muda ao longo da rota, então inicial e final coincidem.
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.0, 10.0);
+
+        assert!((final_bearing(&vertex1, &vertex2) - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_final_bearing_differs_from_initial_on_curved_route() {
+        // Numa rota de grande círculo fora do equador o rumo varia, então o rumo final diverge
+        // do inicial mesmo sendo ambos, em módulo, próximos de leste.
+        let vertex1 = Vertex::new(40.0, -10.0);
+        let vertex2 = Vertex::new(40.0, 10.0);
+
+        let initial = initial_bearing(&vertex1, &vertex2);
+        let final_ = final_bearing(&vertex1, &vertex2);
+
+        assert!((initial - final_).abs() > 1.0);
+    }
 
-    let distance = SEMI_MINOR_AXIS_LENGTH * a_term * (sigma - delta_sigma);
+    #[test]
+    fn test_meridional_distance_is_zero_at_equator() {
+        assert!(meridional_distance(0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_meridional_distance_increases_with_latitude() {
+        let at_10 = meridional_distance(10.0);
+        let at_45 = meridional_distance(45.0);
+        let at_80 = meridional_distance(80.0);
+
+        assert!(at_10 > 0.0);
+        assert!(at_45 > at_10);
+        assert!(at_80 > at_45);
+    }
+
+    #[test]
+    fn test_meridional_distance_is_odd_in_latitude() {
+        let north = meridional_distance(37.0);
+        let south = meridional_distance(-37.0);
+
+        assert!((north + south).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_distance_unit_conversions() {
+        let distance = Distance::from_meters(1609.344);
+
+        assert!((distance.miles() - 1.0).abs() < 1e-9);
+        assert!((distance.nautical_miles() - 0.868976).abs() < 1e-5);
+        assert!((distance.feet() - 5280.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_distance_unit_conversions_round_trip_kilometers() {
+        let distance = Distance::from_kilometers(42.195);
 
-    Ok(distance)
+        assert!((distance.meters() - 42_195.0).abs() < 1e-6);
+        assert!((distance.miles() - 26.2188).abs() < 1e-3);
+        assert!((distance.nautical_miles() - 22.7835).abs() < 1e-3);
+        assert!((distance.feet() - 138_435.0).abs() < 1.0);
+    }
 }
@@ -7,7 +7,10 @@
 //! - [`conversion`]: Contém funções para conversões comuns, como graus para radianos e vice-versa,
 //!   necessárias para cálculos geográficos.
 //! - [`distance`]: Fornece algoritmos para calcular distâncias entre dois pontos na superfície da Terra,
-//!   usando fórmulas como Haversine e Vincenty.
+//!   usando fórmulas como Haversine e o algoritmo geodésico de Karney.
+//! - [`nvector`]: Representação de posições como vetores unitários 3D, numericamente estável em
+//!   todo o domínio (polos e pontos antipodais inclusive); serve de base para distância,
+//!   interpolação e cálculos de rota que a representação lat/lon não trata bem.
 //!
 //! ## Objetivo
 //! Este módulo é projetado para lidar com cálculos geográficos de forma eficiente e modular,
@@ -17,7 +20,7 @@
 //!
 //! ```rust
 //! use RustSpatial::math::conversion::degrees_to_radians;
-//! use RustSpatial::math::distance::{calculate_earth_radius_distance_haversine, calculate_earth_radius_distance_vincenty};
+//! use RustSpatial::math::distance::{calculate_earth_radius_distance_haversine, calculate_earth_radius_distance_geodesic};
 //! use RustSpatial::geometries::vertex::Vertex;
 //!
 //! // Conversão de graus para radianos
@@ -28,13 +31,12 @@
 //! let vertex1 = Vertex::new(10.0, 20.0);
 //! let vertex2 = Vertex::new(15.0, 25.0);
 //! let haversine_distance = calculate_earth_radius_distance_haversine(&vertex1, &vertex2);
-//! println!("Distância aproximada (Haversine): {:.2} km", haversine_distance);
+//! println!("Distância aproximada (Haversine): {:.2} km", haversine_distance.kilometers());
 //!
-//! // Cálculo de distância usando Vincenty
-//! match calculate_earth_radius_distance_vincenty(&vertex1, &vertex2) {
-//!     Ok(vincenty_distance) => println!("Distância precisa (Vincenty): {:.2} m", vincenty_distance),
-//!     Err(err) => println!("Erro no cálculo de Vincenty: {}", err),
-//! }
+//! // Cálculo de distância preciso usando o algoritmo geodésico, convergente para qualquer par
+//! // de pontos, incluindo os antipodais.
+//! let geodesic_distance = calculate_earth_radius_distance_geodesic(&vertex1, &vertex2);
+//! println!("Distância precisa (geodésica): {:.2} m", geodesic_distance);
 //! ```
 //!
 //! ## Notas
@@ -43,3 +45,4 @@
 
 pub mod conversion;
 pub mod distance;
+pub mod nvector;
@@ -0,0 +1,281 @@
+//! Este módulo fornece uma representação alternativa de posições geográficas usando vetores
+//! unitários em três dimensões (n-vectors), conforme descrito por Gade (2010).
+//!
+//! Ao contrário da fórmula de Haversine, a representação por n-vector não tem singularidades:
+//! ela permanece numericamente estável em qualquer ponto da esfera, incluindo os polos exatos
+//! e pares de pontos antipodais, onde a fórmula de Vincenty pode deixar de convergir.
+//!
+//! # Notas
+//! - `n = (cos(lat) · cos(lon), cos(lat) · sin(lon), sin(lat))` é um vetor unitário apontando
+//!   do centro da Terra em direção ao ponto na superfície.
+//! - A distância de grande círculo entre dois n-vectors é `R · atan2(|n1 × n2|, n1 · n2)`, bem
+//!   condicionada para qualquer par de pontos.
+
+use crate::{
+    consts::EARTH_RADIUS_KM,
+    geometries::{line::Line, vertex::Vertex},
+};
+
+use super::{conversion::degrees_to_radians, distance::Distance};
+
+/// Vetor unitário em três dimensões representando uma posição na superfície da Terra.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NVector {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl NVector {
+    /// Converte um `Vertex` (latitude/longitude em graus) para seu n-vector correspondente.
+    pub fn from_vertex(vertex: &Vertex) -> Self {
+        let lat_rad = degrees_to_radians(vertex.latitude);
+        let lon_rad = degrees_to_radians(vertex.longitude);
+
+        Self {
+            x: lat_rad.cos() * lon_rad.cos(),
+            y: lat_rad.cos() * lon_rad.sin(),
+            z: lat_rad.sin(),
+        }
+    }
+
+    /// Converte o n-vector de volta para um `Vertex` (latitude/longitude em graus).
+    pub fn to_vertex(&self) -> Vertex {
+        let lat_rad = self.z.atan2((self.x * self.x + self.y * self.y).sqrt());
+        let lon_rad = self.y.atan2(self.x);
+
+        Vertex::new(lat_rad.to_degrees(), lon_rad.to_degrees())
+    }
+
+    fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+
+    fn normalized(&self) -> Self {
+        self.scale(1.0 / self.norm())
+    }
+}
+
+/// Calcula a distância de grande círculo entre dois vértices usando a representação n-vector.
+///
+/// Numericamente estável em todo o domínio, incluindo os polos e pontos antipodais, onde a
+/// fórmula de Haversine perde precisão e a iteração de Vincenty pode não convergir.
+pub fn distance(a: &Vertex, b: &Vertex) -> Distance {
+    let na = NVector::from_vertex(a);
+    let nb = NVector::from_vertex(b);
+
+    let angular_distance = na.cross(&nb).norm().atan2(na.dot(&nb));
+
+    Distance::from_kilometers(EARTH_RADIUS_KM * angular_distance)
+}
+
+/// Calcula o ponto médio de grande círculo entre dois vértices.
+pub fn midpoint(a: &Vertex, b: &Vertex) -> Vertex {
+    let na = NVector::from_vertex(a);
+    let nb = NVector::from_vertex(b);
+
+    na.add(&nb).normalized().to_vertex()
+}
+
+/// Interpola entre dois vértices ao longo do grande círculo que os une, usando interpolação
+/// esférica (slerp).
+///
+/// `t = 0.0` retorna `a`, `t = 1.0` retorna `b`, e valores intermediários retornam o ponto
+/// correspondente à fração `t` do caminho angular entre eles.
+pub fn interpolate(a: &Vertex, b: &Vertex, t: f64) -> Vertex {
+    let na = NVector::from_vertex(a);
+    let nb = NVector::from_vertex(b);
+
+    let angular_distance = na.cross(&nb).norm().atan2(na.dot(&nb));
+
+    if angular_distance == 0.0 {
+        return *a;
+    }
+
+    let scale_a = ((1.0 - t) * angular_distance).sin() / angular_distance.sin();
+    let scale_b = (t * angular_distance).sin() / angular_distance.sin();
+
+    na.scale(scale_a).add(&nb.scale(scale_b)).to_vertex()
+}
+
+/// Calcula a distância fora da rota (cross-track) de um ponto até a rota de grande círculo
+/// definida por uma `Line`.
+///
+/// É a distância perpendicular entre o ponto e o grande círculo que passa pelos dois vértices
+/// da linha, positiva à direita da rota e negativa à esquerda.
+pub fn cross_track_distance(point: &Vertex, line: &Line) -> Distance {
+    let n_start = NVector::from_vertex(&line.starting_vertex);
+    let n_end = NVector::from_vertex(&line.ending_vertex);
+    let n_point = NVector::from_vertex(point);
+
+    // Normal do plano do grande círculo da linha.
+    let path_normal = n_start.cross(&n_end).normalized();
+
+    // `path_normal` aponta para a esquerda da rota (regra da mão direita de start para end),
+    // então um ponto à direita tem produto escalar negativo com ela; invertemos o sinal para
+    // que a distância fique positiva à direita e negativa à esquerda, como documentado acima.
+    let angular_distance = -(path_normal.dot(&n_point)).asin();
+
+    Distance::from_kilometers(EARTH_RADIUS_KM * angular_distance)
+}
+
+/// Calcula a distância ao longo da rota (along-track) do início da `Line` até o ponto da
+/// superfície mais próximo de `point` sobre o grande círculo da linha.
+pub fn along_track_distance(point: &Vertex, line: &Line) -> Distance {
+    let n_start = NVector::from_vertex(&line.starting_vertex);
+    let n_end = NVector::from_vertex(&line.ending_vertex);
+    let n_point = NVector::from_vertex(point);
+
+    let path_normal = n_start.cross(&n_end).normalized();
+
+    // Projeta o ponto no grande círculo da linha removendo a componente ao longo da normal.
+    let point_on_path = n_point
+        .add(&path_normal.scale(-path_normal.dot(&n_point)))
+        .normalized();
+
+    let angular_distance = n_start.cross(&point_on_path).norm().atan2(n_start.dot(&point_on_path));
+
+    Distance::from_kilometers(EARTH_RADIUS_KM * angular_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_along_equator() {
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.0, 1.0);
+
+        let expected = EARTH_RADIUS_KM * std::f64::consts::PI / 180.0;
+        assert!((distance(&vertex1, &vertex2).kilometers() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_distance_between_coincident_points_is_zero() {
+        let vertex = Vertex::new(12.0, 34.0);
+
+        assert_eq!(distance(&vertex, &vertex).kilometers(), 0.0);
+    }
+
+    #[test]
+    fn test_distance_between_antipodal_points_is_half_circumference() {
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.0, 180.0);
+
+        let expected = EARTH_RADIUS_KM * std::f64::consts::PI;
+        assert!((distance(&vertex1, &vertex2).kilometers() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_midpoint_of_equator_quarter() {
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.0, 90.0);
+
+        let midpoint_vertex = midpoint(&vertex1, &vertex2);
+
+        assert!((midpoint_vertex.latitude - 0.0).abs() < 1e-9);
+        assert!((midpoint_vertex.longitude - 45.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_at_t_zero_returns_start() {
+        let vertex1 = Vertex::new(10.0, 20.0);
+        let vertex2 = Vertex::new(30.0, 40.0);
+
+        assert_eq!(interpolate(&vertex1, &vertex2, 0.0), vertex1);
+    }
+
+    #[test]
+    fn test_interpolate_at_t_one_returns_end() {
+        let vertex1 = Vertex::new(10.0, 20.0);
+        let vertex2 = Vertex::new(30.0, 40.0);
+
+        let result = interpolate(&vertex1, &vertex2, 1.0);
+        assert!((result.latitude - vertex2.latitude).abs() < 1e-9);
+        assert!((result.longitude - vertex2.longitude).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_at_t_half_matches_midpoint() {
+        let vertex1 = Vertex::new(0.0, 0.0);
+        let vertex2 = Vertex::new(0.0, 90.0);
+
+        let interpolated = interpolate(&vertex1, &vertex2, 0.5);
+        let expected = midpoint(&vertex1, &vertex2);
+
+        assert!((interpolated.latitude - expected.latitude).abs() < 1e-9);
+        assert!((interpolated.longitude - expected.longitude).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_coincident_points_returns_start() {
+        let vertex = Vertex::new(15.0, -40.0);
+
+        assert_eq!(interpolate(&vertex, &vertex, 0.5), vertex);
+    }
+
+    #[test]
+    fn test_cross_track_distance_on_route_is_zero() {
+        let line = Line::new(Vertex::new(0.0, 0.0), Vertex::new(0.0, 10.0)).unwrap();
+        let point = Vertex::new(0.0, 5.0);
+
+        assert!(cross_track_distance(&point, &line).kilometers().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cross_track_distance_sign_right_vs_left() {
+        // Seguindo o equador para leste, norte fica à esquerda e sul à direita da rota.
+        let line = Line::new(Vertex::new(0.0, 0.0), Vertex::new(0.0, 10.0)).unwrap();
+        let point_to_the_right = Vertex::new(-1.0, 5.0);
+        let point_to_the_left = Vertex::new(1.0, 5.0);
+
+        assert!(cross_track_distance(&point_to_the_right, &line).kilometers() > 0.0);
+        assert!(cross_track_distance(&point_to_the_left, &line).kilometers() < 0.0);
+    }
+
+    #[test]
+    fn test_along_track_distance_for_point_off_great_circle() {
+        let line = Line::new(Vertex::new(0.0, 0.0), Vertex::new(0.0, 10.0)).unwrap();
+        let point = Vertex::new(1.0, 5.0);
+
+        let expected = EARTH_RADIUS_KM * 5.0_f64.to_radians();
+        assert!((along_track_distance(&point, &line).kilometers() - expected).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_along_track_distance_at_start_is_zero() {
+        let line = Line::new(Vertex::new(0.0, 0.0), Vertex::new(0.0, 10.0)).unwrap();
+        let point = Vertex::new(0.0, 0.0);
+
+        assert!(along_track_distance(&point, &line).kilometers().abs() < 1e-6);
+    }
+}